@@ -0,0 +1,40 @@
+use criterion::{black_box, BenchmarkId, Criterion};
+
+use aoc_rust::day13::bus::{from_str, earliest, earliest_v2, earliest_v3, earliest_v3_rev};
+
+mod report;
+
+type Earliest = fn(Vec<(usize, u128)>, fn(u128) -> bool) -> (u128, usize);
+
+const EXAMPLE: &str = "1789,37,47,1889";
+const INPUT: &str = "19,x,x,x,x,x,x,x,x,x,x,x,x,37,x,x,x,x,x,599,x,29,x,x,x,x,x,x,x,x,x,x,x,x,x,x,17,x,x,x,x,x,23,x,x,x,x,x,x,x,761,x,x,x,x,x,x,x,x,x,41,x,x,13";
+
+/// Every implementation of day thirteen part two on the same schedule, grouped so criterion compares them side by side
+fn compare(c: &mut Criterion, name: &str, schedule: &str, implementations: &[(&str, Earliest)]) {
+    let buses = from_str(schedule).unwrap();
+    let mut group = c.benchmark_group(name);
+    for (implementation, earliest) in implementations {
+        group.bench_with_input(BenchmarkId::from_parameter(implementation), &buses, |b, buses| {
+            b.iter(|| earliest(black_box(buses.clone()), |_| false).0)
+        });
+    }
+    group.finish();
+}
+
+fn main() {
+    let mut c = Criterion::default().configure_from_args();
+    compare(&mut c, "earliest_example", EXAMPLE, &[
+        ("earliest", earliest),
+        ("earliest_v2", earliest_v2),
+        ("earliest_v3", earliest_v3),
+        ("earliest_v3_rev", earliest_v3_rev),
+    ]);
+    // `earliest` is only compared on the example, it takes too long on the real schedule
+    compare(&mut c, "earliest_input", INPUT, &[
+        ("earliest_v2", earliest_v2),
+        ("earliest_v3", earliest_v3),
+        ("earliest_v3_rev", earliest_v3_rev),
+    ]);
+    c.final_summary();
+    report::write();
+}
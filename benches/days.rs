@@ -0,0 +1,74 @@
+use criterion::{black_box, Criterion};
+
+use aoc_rust::input;
+use aoc_rust::solution::Solution;
+use aoc_rust::day1::Day1;
+use aoc_rust::day2::Day2;
+use aoc_rust::day3::Day3;
+use aoc_rust::day4::Day4;
+use aoc_rust::day5::Day5;
+use aoc_rust::day6::Day6;
+use aoc_rust::day7::Day7;
+use aoc_rust::day8::Day8;
+use aoc_rust::day9::Day9;
+use aoc_rust::day10::Day10;
+use aoc_rust::day11::Day11;
+use aoc_rust::day12::Day12;
+use aoc_rust::day13::Day13;
+use aoc_rust::day14::Day14;
+use aoc_rust::day15::Day15;
+use aoc_rust::day16::Day16;
+use aoc_rust::day17::Day17;
+use aoc_rust::day18::Day18;
+use aoc_rust::day19::Day19;
+use aoc_rust::day20::Day20;
+use aoc_rust::day21::Day21;
+use aoc_rust::day22::Day22;
+use aoc_rust::day23::Day23;
+
+mod report;
+
+/// Parse, part one and part two of a day on its checked-in input, as `dayNN/parse`, `dayNN/part_one` and `dayNN/part_two`
+fn bench_day<S: Solution>(c: &mut Criterion, samples: usize) {
+    let text = input::read(input::open(S::INPUT).unwrap()).unwrap();
+    let input = S::parse(&text).unwrap();
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    group.sample_size(samples);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&text))));
+    group.bench_function("part_one", |b| b.iter(|| S::part_one(black_box(&input))));
+    group.bench_function("part_two", |b| b.iter(|| S::part_two(black_box(&input))));
+    group.finish();
+}
+
+/// Samples per benchmark, the days running for seconds get criterion's minimum
+const SAMPLES: usize = 100;
+const SLOW_SAMPLES: usize = 10;
+
+fn main() {
+    let mut c = Criterion::default().configure_from_args();
+    bench_day::<Day1>(&mut c, SAMPLES);
+    bench_day::<Day2>(&mut c, SAMPLES);
+    bench_day::<Day3>(&mut c, SAMPLES);
+    bench_day::<Day4>(&mut c, SAMPLES);
+    bench_day::<Day5>(&mut c, SAMPLES);
+    bench_day::<Day6>(&mut c, SAMPLES);
+    bench_day::<Day7>(&mut c, SAMPLES);
+    bench_day::<Day8>(&mut c, SAMPLES);
+    bench_day::<Day9>(&mut c, SAMPLES);
+    bench_day::<Day10>(&mut c, SAMPLES);
+    bench_day::<Day11>(&mut c, SLOW_SAMPLES);
+    bench_day::<Day12>(&mut c, SAMPLES);
+    bench_day::<Day13>(&mut c, SAMPLES);
+    bench_day::<Day14>(&mut c, SAMPLES);
+    bench_day::<Day15>(&mut c, SLOW_SAMPLES);
+    bench_day::<Day16>(&mut c, SAMPLES);
+    bench_day::<Day17>(&mut c, SLOW_SAMPLES);
+    bench_day::<Day18>(&mut c, SAMPLES);
+    bench_day::<Day19>(&mut c, SAMPLES);
+    bench_day::<Day20>(&mut c, SAMPLES);
+    bench_day::<Day21>(&mut c, SAMPLES);
+    bench_day::<Day22>(&mut c, SAMPLES);
+    bench_day::<Day23>(&mut c, SLOW_SAMPLES);
+    c.final_summary();
+    report::write();
+}
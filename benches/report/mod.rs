@@ -0,0 +1,60 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde_json::{json, Value};
+
+/// Where criterion keeps its results, `CRITERION_HOME` or `target/criterion`
+fn criterion_home() -> PathBuf {
+    env::var_os("CRITERION_HOME").map(PathBuf::from)
+        .unwrap_or_else(|| Path::new("target").join("criterion"))
+}
+
+/// Every `<group>/<function>/new/estimates.json` criterion has written so far
+fn estimates(home: &Path) -> Vec<(String, Value)> {
+    let mut found = Vec::new();
+    for group in fs::read_dir(home).into_iter().flatten().flatten() {
+        for function in fs::read_dir(group.path()).into_iter().flatten().flatten() {
+            let file = function.path().join("new").join("estimates.json");
+            if let Some(estimates) = fs::read_to_string(&file).ok().and_then(|text| serde_json::from_str(&text).ok()) {
+                let id = format!("{}/{}", group.file_name().to_string_lossy(), function.file_name().to_string_lossy());
+                found.push((id, estimates));
+            }
+        }
+    }
+    found.sort_by(|(left, _), (right, _)| left.cmp(right));
+    found
+}
+
+/// Commit the benchmarks ran on, when run inside a git checkout
+fn commit() -> Option<String> {
+    let output = Command::new("git").args(["rev-parse", "HEAD"]).output().ok()?;
+    match output.status.success() {
+        true => Some(String::from_utf8_lossy(&output.stdout).trim().to_string()),
+        false => None,
+    }
+}
+
+/// Collects the latest estimates of every benchmark into `report.json` next to criterion's own results,
+/// so runs on different commits can be compared by a script
+pub fn write() {
+    let home = criterion_home();
+    let benchmarks = estimates(&home).into_iter()
+        .map(|(id, estimates)| json!({
+            "id": id,
+            "mean_ns": estimates["mean"]["point_estimate"],
+            "median_ns": estimates["median"]["point_estimate"],
+            "std_dev_ns": estimates["std_dev"]["point_estimate"],
+        }))
+        .collect::<Vec<Value>>();
+    let report = json!({
+        "commit": commit(),
+        "benchmarks": benchmarks,
+    });
+    let path = home.join("report.json");
+    match fs::write(&path, serde_json::to_string_pretty(&report).unwrap()) {
+        Ok(()) => println!("Report written to {}", path.display()),
+        Err(error) => eprintln!("Can't write {}: {}", path.display(), error),
+    }
+}
@@ -0,0 +1,297 @@
+use tracing::{debug, trace};
+use crate::error::{ParseError, parse_lines, number};
+
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Instruction {
+    Acc { increment: i32 },
+    Jmp { increment: i32 },
+    Nop { increment: i32 },
+}
+
+impl Instruction {
+    pub fn from_line<P>(line: P) -> Result<Instruction, ParseError> where P: AsRef<str> {
+        let line = line.as_ref();
+        match line.split_once(char::is_whitespace) {
+            Some(("acc", x)) => Ok(Instruction::Acc { increment: number(line, x)? }),
+            Some(("jmp", x)) => Ok(Instruction::Jmp { increment: number(line, x)? }),
+            Some(("nop", x)) => Ok(Instruction::Nop { increment: number(line, x)? }),
+            Some((operation, _)) => Err(ParseError::new(line, operation, "`acc`, `jmp` or `nop`")),
+            None => Err(ParseError::new(line, line, "an instruction like `acc +1`")),
+        }
+    }
+}
+
+pub fn from_lines(lines: &str) -> Result<Vec<(Instruction, usize)>, ParseError> {
+    parse_lines(lines, |line| Instruction::from_line(line)).map(|instructions| instructions.into_iter().map(|instr| (instr, 0)).collect())
+}
+
+#[derive(Clone, Default)]
+pub struct Code {
+    accumulator: i32,
+    instructions: Vec<(Instruction, usize)>,
+}
+
+impl Code {
+    pub fn from_string<P>(lines: P) -> Result<Code, ParseError> where P: AsRef<str> {
+        Ok(Code {
+            accumulator: 0,
+            instructions: from_lines(lines.as_ref())?,
+        })
+    }
+
+    pub fn execute(&mut self) -> i32 {
+        self.accumulator = 0;
+        let mut index: usize = 0;
+        loop {
+            let ins = &mut self.instructions[index];
+            match &ins {
+                (Instruction::Jmp { increment: inc @ 0..=i32::MAX },  0) => {
+                    index += inc.unsigned_abs() as usize;
+                }
+                (Instruction::Jmp { increment: inc @ i32::MIN..0 },  0) => {
+                    index -= inc.unsigned_abs() as usize;
+                }
+                (Instruction::Acc { increment: inc },  0) => {
+                    index += 1;
+                    self.accumulator += *inc;
+                }
+                (Instruction::Nop { increment: _ },  0) => {
+                    index += 1;
+                }
+                (inst, cnt) => {
+                    debug!("Breaking at: {:?} used {} times", inst, cnt);
+                    break;
+                }
+            };
+
+            let (inst, cnt) = &ins;
+            trace!("{:?} used {} times", inst, cnt);
+            ins.1 += 1;
+        }
+        self.accumulator
+    }
+    pub fn execute_correctly(&mut self) -> Result<usize, usize> {
+        self.accumulator = 0;
+        let mut index: usize = 0;
+        loop {
+            if index == self.instructions.len() {
+                return Ok(index);
+            }
+            let ins = &mut self.instructions[index];
+            match &ins {
+                (Instruction::Jmp { increment: inc @ 0..=i32::MAX },  0) => {
+                    index += inc.unsigned_abs() as usize;
+                }
+                (Instruction::Jmp { increment: inc @ i32::MIN..0 },   0) => {
+                    index -= inc.unsigned_abs() as usize;
+                }
+                (Instruction::Acc { increment: inc },   0) => {
+                    index += 1;
+                    self.accumulator += *inc;
+                }
+                (Instruction::Nop { increment: _ },  0) => {
+                    index += 1;
+                }
+                (inst, cnt) => {
+                    debug!("Breaking at {}: {:?} used {} times", index, inst, cnt);
+                    return Err(index);
+                }
+            };
+
+            let (inst, cnt) = &ins;
+            trace!("{:?} used {} times", inst, cnt);
+            ins.1 += 1;
+        }
+    }
+}
+
+/// Where a [`Console`] stopped running
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Halt {
+    /// Past the last instruction, runs on when the one it points to is added
+    Waiting,
+    /// About to run an instruction for the second time, it never runs again
+    Looped,
+    /// Jumped before the first instruction
+    OutOfBounds,
+}
+
+/// A program fed an instruction at a time, run as far as it goes after each one
+pub struct Console {
+    code: Code,
+    pointer: usize,
+    halt: Halt,
+}
+
+impl Default for Console {
+    fn default() -> Self {
+        Console { code: Code::default(), pointer: 0, halt: Halt::Waiting }
+    }
+}
+
+impl Console {
+    pub fn push(&mut self, instruction: Instruction) -> Halt {
+        self.code.instructions.push((instruction, 0));
+        while self.halt == Halt::Waiting && self.pointer < self.code.instructions.len() {
+            let (instruction, count) = &mut self.code.instructions[self.pointer];
+            if *count > 0 {
+                debug!("Looped at {}: {:?}", self.pointer, instruction);
+                self.halt = Halt::Looped;
+                break;
+            }
+            *count += 1;
+            let next = match *instruction {
+                Instruction::Acc { increment } => {
+                    self.code.accumulator += increment;
+                    Some(self.pointer + 1)
+                }
+                Instruction::Jmp { increment } => self.pointer.checked_add_signed(increment as isize),
+                Instruction::Nop { .. } => Some(self.pointer + 1),
+            };
+            match next {
+                Some(next) => self.pointer = next,
+                None => self.halt = Halt::OutOfBounds,
+            }
+        }
+        self.halt
+    }
+    pub fn accumulator(&self) -> i32 {
+        self.code.accumulator
+    }
+    /// Index of the next instruction to run
+    pub fn pointer(&self) -> usize {
+        self.pointer
+    }
+    pub fn halt(&self) -> Halt {
+        self.halt
+    }
+    /// Every instruction so far, with how many times it ran
+    pub fn instructions(&self) -> &[(Instruction, usize)] {
+        &self.code.instructions
+    }
+}
+
+pub fn try_until_correct(code: &mut Code) -> i32 {
+    let mut index: usize = 0;
+    {
+        let mut code = code.clone().to_owned();
+        if code.execute_correctly().is_ok() { return code.accumulator }
+    }
+
+
+    loop{
+        let mut changed_code = code.clone().to_owned();
+        //Change instruction loop
+        loop {
+            index += 1;
+            match changed_code.instructions[index] {
+                (Instruction::Jmp { increment: inc }, count) => {
+                    debug!("Attempt {}, modifying {:?}", index, changed_code.instructions[index]);
+                    changed_code.instructions[index] = (Instruction::Nop { increment: inc }, count);
+                    break
+                }
+                (Instruction::Nop { increment: inc }, count) => {
+                    debug!("Attempt {}, modifying {:?}", index, changed_code.instructions[index]);
+                    changed_code.instructions[index] = (Instruction::Nop { increment: inc }, count);
+                    break
+                }
+                (_,_) => {}
+            }
+        }
+        if changed_code.execute_correctly().is_ok() { return changed_code.accumulator }
+    }
+
+}
+
+
+#[cfg(test)]
+mod assembler_tests {
+    use super::{Instruction, Code, Console, Halt, try_until_correct, from_lines};
+
+    #[test]
+    fn parser() {
+        assert_eq!(Instruction::from_line("nop +0"), Ok(Instruction::Nop { increment: 0 }));
+
+        assert_eq!(Instruction::from_line("acc +1"), Ok(Instruction::Acc { increment: 1 }));
+        assert_eq!(Instruction::from_line("acc -99"), Ok(Instruction::Acc { increment: -99 }));
+
+        assert_eq!(Instruction::from_line("jmp +4"), Ok(Instruction::Jmp { increment: 4 }));
+        assert_eq!(Instruction::from_line("jmp -3"), Ok(Instruction::Jmp { increment: -3 }));
+    }
+
+    #[test]
+    fn malformed_instruction() {
+        assert_eq!(Instruction::from_line("mul +2").unwrap_err().expected, "`acc`, `jmp` or `nop`");
+        assert_eq!(Instruction::from_line("acc").unwrap_err().expected, "an instruction like `acc +1`");
+        let error = from_lines("nop +0\njmp +x").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 5, "+x"));
+    }
+
+    #[test]
+    fn from_example() {
+        let input = r#"nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6"#;
+        let mut code = Code::from_string(input).unwrap();
+        assert_eq!(code.execute(), 5);
+        // code.execute();
+    }
+
+    #[test]
+    fn incorrectly_executed() {
+        let input = r#"nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6"#;
+        let mut code = Code::from_string(input).unwrap();
+        assert!(code.execute_correctly().is_err());
+        assert_eq!(code.accumulator, 5);
+        assert_eq!(try_until_correct(&mut Code::from_string(input).unwrap()), 8);
+    }
+
+    #[test]
+    fn correctly_executed() {
+        let input = r#"nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+nop -4
+acc +6"#;
+        let mut code = Code::from_string(input).unwrap();
+        assert!(code.execute_correctly().is_ok());
+        assert_eq!(code.accumulator, 8);
+        assert_eq!(try_until_correct(&mut Code::from_string(input).unwrap()), code.accumulator);
+    }
+
+    #[test]
+    fn console_runs_as_far_as_it_can() {
+        let mut console = Console::default();
+        let mut feed = |line: &str| console.push(Instruction::from_line(line).unwrap());
+        assert_eq!(feed("nop +0"), Halt::Waiting);
+        assert_eq!(feed("jmp +2"), Halt::Waiting);
+        assert_eq!(feed("acc +100"), Halt::Waiting);
+        assert_eq!(feed("acc +3"), Halt::Waiting);
+        assert_eq!(feed("jmp -4"), Halt::Looped);
+        assert_eq!(feed("acc +1"), Halt::Looped);
+        assert_eq!((console.accumulator(), console.pointer()), (3, 0));
+        assert_eq!(console.instructions()[2].1, 0);
+
+        let mut console = Console::default();
+        assert_eq!(console.push(Instruction::Jmp { increment: -1 }), Halt::OutOfBounds);
+    }
+}
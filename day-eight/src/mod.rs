@@ -0,0 +1,71 @@
+pub mod assembler;
+
+use crate::error::{ParseError, NoAnswer};
+use crate::solution::Solution;
+use crate::generate::Rng;
+use crate::repl::Session;
+use rand::Rng as _;
+use assembler::{Code, Console, Halt, Instruction, try_until_correct};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const INPUT: &'static str = "./day-eight/asm.dat";
+    type Input = Code;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Code, ParseError> {
+        Code::from_string(input)
+    }
+    fn part_one(code: &Code) -> Result<i32, NoAnswer> {
+        Ok(code.clone().execute())
+    }
+    fn part_two(code: &Code) -> Result<i32, NoAnswer> {
+        Ok(try_until_correct(&mut code.clone()))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Jumps only go forward up to the last instruction, which jumps back. Flipping it to `nop` ends the program.
+        let last = size.max(2) - 1;
+        let mut program = (0..last).map(|index| match rng.gen_range(0..3) {
+            0 => format!("acc {:+}", rng.gen_range(-50..=50)),
+            1 => format!("nop {:+}", rng.gen_range(-50..=50)),
+            _ => format!("jmp {:+}", rng.gen_range(1..=last - index)),
+        }).collect::<Vec<_>>();
+        program.push(format!("jmp -{}", rng.gen_range(1..=last)));
+        program.join("\n")
+    }
+}
+
+/// Instruction as written in a program
+fn assembly(instruction: &Instruction) -> String {
+    match instruction {
+        Instruction::Acc { increment } => format!("acc {:+}", increment),
+        Instruction::Jmp { increment } => format!("jmp {:+}", increment),
+        Instruction::Nop { increment } => format!("nop {:+}", increment),
+    }
+}
+
+fn status(console: &Console) -> String {
+    match console.halt() {
+        Halt::Waiting => format!("accumulator {}, next instruction {}", console.accumulator(), console.pointer()),
+        Halt::Looped => format!("looped at instruction {}, accumulator {}", console.pointer(), console.accumulator()),
+        Halt::OutOfBounds => format!("jumped before the first instruction, accumulator {}", console.accumulator()),
+    }
+}
+
+impl Session for Console {
+    fn eval(&mut self, line: &str) -> Result<String, ParseError> {
+        self.push(Instruction::from_line(line)?);
+        Ok(status(self))
+    }
+    fn state(&self) -> String {
+        let mut state = status(self);
+        for (index, (instruction, count)) in self.instructions().iter().enumerate() {
+            let marker = if index == self.pointer() { '>' } else { ' ' };
+            state.push_str(&format!("\n{}{:>3} {:<8} ran {}", marker, index, assembly(instruction), count));
+        }
+        state
+    }
+}
@@ -1,12 +1,12 @@
-use std::str::FromStr;
 use itertools::Itertools;
 
-use debug_print::{debug_print};
+use tracing::trace;
+use crate::error::ParseError;
 
-type ChildNode<T> = Option<Box<BTNode<T>>>;
+pub type ChildNode<T> = Option<Box<BTNode<T>>>;
 
 #[derive(Debug, Eq, PartialEq)]
-struct BTNode<T> {
+pub struct BTNode<T> {
     left: ChildNode<T>,
     right: ChildNode<T>,
     op: Op<T>,
@@ -15,7 +15,7 @@ struct BTNode<T> {
 impl BTNode<i32> {
     pub fn new(op: Op<i32>, l: BTNode<i32>, r: BTNode<i32>) -> Self {
         BTNode::<i32> {
-            op: op,
+            op,
             left: Some(Box::new(l)),
             right: Some(Box::new(r)),
         }
@@ -38,21 +38,21 @@ impl BTNode<i32> {
 // }
 
 #[derive(Debug, Eq, PartialEq)]
-enum Op<T> {
+pub enum Op<T> {
     Add,
     Mul,
     Val(T),
 }
 
-fn AddNode(l: BTNode<i32>, r: BTNode<i32>) -> BTNode<i32> {
+pub fn add_node(l: BTNode<i32>, r: BTNode<i32>) -> BTNode<i32> {
     BTNode::new(Op::Add, l, r)
 }
 
-fn MulNode(l: BTNode<i32>, r: BTNode<i32>) -> BTNode<i32> {
+pub fn mul_node(l: BTNode<i32>, r: BTNode<i32>) -> BTNode<i32> {
     BTNode::new(Op::Mul, l, r)
 }
 
-fn ValNode(value: i32) -> BTNode<i32> {
+pub fn val_node(value: i32) -> BTNode<i32> {
     BTNode {
         left: None,
         right: None,
@@ -60,15 +60,15 @@ fn ValNode(value: i32) -> BTNode<i32> {
     }
 }
 
-struct BinaryTree<T> {
-    head: Option<BTNode<T>>
+pub struct BinaryTree<T> {
+    pub head: Option<BTNode<T>>
 }
 
 impl BinaryTree<i32> {
     pub fn new(head: BTNode<i32>) -> Self {
         BinaryTree::<i32> { head: Some(head) }
     }
-    pub fn collapse(node: &Box<BTNode<i32>>) -> i32 {
+    pub fn collapse(node: &BTNode<i32>) -> i32 {
         let mut r: Option<i32> = None;
         let mut l: Option<i32> = None;
 
@@ -80,14 +80,8 @@ impl BinaryTree<i32> {
             r = Some(BinaryTree::collapse(right));
         }
 
-        let l = match l {
-            Some(x) => x,
-            None => 0
-        };
-        let r = match r {
-            Some(x) => x,
-            None => 0
-        };
+        let l = l.unwrap_or_default();
+        let r = r.unwrap_or_default();
 
         match node.op {
             Op::Add => { l + r }
@@ -97,35 +91,49 @@ impl BinaryTree<i32> {
     }
 }
 
-impl FromStr for BinaryTree<i32> {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        unimplemented!()
-    }
-}
-
 #[derive(Debug, Eq, PartialEq)]
-enum Oper<T> {
+enum Oper {
     Add,
     Mul,
-    Val(T),
     Start,
 }
 
+/// Checks that `s` is made of single digits, `+`, `*` and balanced parentheses, the only things the evaluators understand
+pub fn check(s: &str) -> Result<(), ParseError> {
+    let mut depth = 0;
+    let mut operand_next = true;
+    for (at, c) in s.char_indices() {
+        let fragment = &s[at..at + c.len_utf8()];
+        match (operand_next, c) {
+            (_, ' ') => {}
+            (true, '0'..='9') => operand_next = false,
+            (true, '(') => depth += 1,
+            (false, '+' | '*') => operand_next = true,
+            (false, ')') if depth > 0 => depth -= 1,
+            (false, ')') => return Err(ParseError::new(s, fragment, "`+` or `*`, there is no `(` to close")),
+            (true, _) => return Err(ParseError::new(s, fragment, "a digit or `(`")),
+            (false, _) => return Err(ParseError::new(s, fragment, "`+`, `*` or `)`")),
+        }
+    }
+    match (operand_next, depth) {
+        (true, _) => Err(ParseError::new(s, &s[s.len()..], "a digit or `(`")),
+        (false, 0) => Ok(()),
+        _ => Err(ParseError::new(s, &s[s.len()..], "`)`")),
+    }
+}
+
 pub fn eval_helper(s: &str) -> i32 {
-    s.split_ascii_whitespace().fold((0, Oper::<i32>::Start), |(mut accum, mut oper), substr| {
+    s.split_ascii_whitespace().fold((0, Oper::Start), |(mut accum, mut oper), substr| {
         if substr.chars().all(|c| c.is_numeric()) {
             match oper {
-                Oper::<i32>::Add => accum += substr.parse::<i32>().unwrap(),
-                Oper::<i32>::Mul => accum *= substr.parse::<i32>().unwrap(),
-                Oper::<i32>::Start => accum = substr.parse::<i32>().unwrap(),
-                _ => panic!("Unexpected input")
+                Oper::Add => accum += substr.parse::<i32>().unwrap(),
+                Oper::Mul => accum *= substr.parse::<i32>().unwrap(),
+                Oper::Start => accum = substr.parse::<i32>().unwrap(),
             }
         } else {
             match substr {
-                "+" => oper = Oper::<i32>::Add,
-                "*" => oper = Oper::<i32>::Mul,
+                "+" => oper = Oper::Add,
+                "*" => oper = Oper::Mul,
                 _ => panic!("Unexpected input")
             }
         }
@@ -138,7 +146,7 @@ pub fn get_subexpression(s: &str) -> &str {
     let mut depth = 0;
     let right_pos = s.chars().dropping(left_pos).position(|c|
         {
-            // debug_print!("Depth: {}, char: {:?}",depth,c);
+            // trace!("Depth: {}, char: {:?}",depth,c);
             match c {
                 '(' => depth += 1,
                 ')' => depth -= 1,
@@ -147,37 +155,35 @@ pub fn get_subexpression(s: &str) -> &str {
             depth == 0
         }
     ).unwrap();
-    // debug_print!("Depth: {}, char: {:?}",depth,c);
+    // trace!("Depth: {}, char: {:?}",depth,c);
     &s[left_pos + 1..left_pos + right_pos]
 }
 
 pub fn evaluate(s: &str) -> i64 {
     let mut ignore = 0;
-    debug_print!("Evaluating expression: {:?}",s);
-    let res = s.chars().enumerate().fold((0, Oper::<i32>::Start), |(mut accum, mut oper), (idx, substr)| {
+    trace!("Evaluating expression: {:?}",s);
+    let res = s.chars().enumerate().fold((0, Oper::Start), |(mut accum, mut oper), (idx, substr)| {
         if ignore != 0 {
             ignore -= 1;
         } else if substr.is_numeric() {
             match oper {
-                Oper::<i32>::Add => accum += (substr.to_digit(10).unwrap() as i64),
-                Oper::<i32>::Mul => accum *= (substr.to_digit(10).unwrap() as i64),
-                Oper::<i32>::Start => accum = (substr.to_digit(10).unwrap() as i64),
-                _ => panic!("Unexpected input")
+                Oper::Add => accum += substr.to_digit(10).unwrap() as i64 ,
+                Oper::Mul => accum *= substr.to_digit(10).unwrap() as i64 ,
+                Oper::Start => accum = substr.to_digit(10).unwrap() as i64 ,
             }
         } else if substr.is_whitespace() {} else {
             match substr {
-                '+' => oper = Oper::<i32>::Add,
-                '*' => oper = Oper::<i32>::Mul,
+                '+' => oper = Oper::Add,
+                '*' => oper = Oper::Mul,
                 '(' => {
                     let subexpression = get_subexpression(&s[idx..]);
                     ignore = subexpression.len() + 2;
-                    debug_print!("Found subexpression: {:?}\n",subexpression);
+                    trace!("Found subexpression: {:?}",subexpression);
                     let subexpression = evaluate(subexpression);
                     match oper {
-                        Oper::<i32>::Add => accum += subexpression,
-                        Oper::<i32>::Mul => accum *= subexpression,
-                        Oper::<i32>::Start => accum = subexpression,
-                        _ => panic!("Unexpected input")
+                        Oper::Add => accum += subexpression,
+                        Oper::Mul => accum *= subexpression,
+                        Oper::Start => accum = subexpression,
                     }
                 }
                 x => panic!("Unexpected input from {}: {} | ignore: {}", s, x, ignore)
@@ -185,93 +191,161 @@ pub fn evaluate(s: &str) -> i64 {
         }
         (accum, oper)
     }).0;
-    debug_print!("| Res: {}\n",res);
+    trace!("| Res: {}",res);
     res
 }
 
 pub fn evaluate_part2(s: &str) -> i64 {
     let mut ignore = 0;
-    debug_print!("Evaluating expression: {:?}",s);
-    let res = s.chars().enumerate().fold((0, Oper::<i32>::Start), |(mut accum, mut oper), (idx, substr)| {
+    trace!("Evaluating expression: {:?}",s);
+    // Addition binds tighter than multiplication, so keep summing until a `*` flushes the sum
+    let (product, sum) = s.chars().enumerate().fold((1, 0), |(mut product, mut sum), (idx, substr)| {
         if ignore != 0 {
             ignore -= 1;
         } else if substr.is_numeric() {
-            match oper {
-                // Oper::<i32>::Add => accum += (substr.to_digit(10).unwrap() as i64),
-                Oper::<i32>::Mul => accum *= {
-                    substr.to_digit(10).unwrap() as i64 },
-                Oper::<i32>::Start => accum = (substr.to_digit(10).unwrap() as i64),
-                _ => panic!("Unexpected input")
-            }
+            sum += substr.to_digit(10).unwrap() as i64;
         } else if substr.is_whitespace() {} else {
             match substr {
-                '+' => {
-                    let subexpression = &s[idx - 2..=idx + 2];
-                    ignore = 2;
-                    // debug_print!("Found subexpression: {:?}\n",subexpression);
-                    let subexpression = evaluate(subexpression);
-                    match oper {
-                        Oper::<i32>::Add => accum += subexpression,
-                        Oper::<i32>::Mul => accum *= subexpression,
-                        Oper::<i32>::Start => accum = subexpression,
-                        _ => panic!("Unexpected input")
-                    }
+                '+' => {}
+                '*' => {
+                    product *= sum;
+                    sum = 0;
                 }
-                '*' => oper = Oper::<i32>::Mul,
                 '(' => {
                     let subexpression = get_subexpression(&s[idx..]);
                     ignore = subexpression.len() + 2;
-                    // debug_print!("Found subexpression: {:?}\n",subexpression);
-                    let subexpression = evaluate_part2(subexpression);
-                    match oper {
-                        Oper::<i32>::Add => accum += subexpression,
-                        Oper::<i32>::Mul => accum *= subexpression,
-                        Oper::<i32>::Start => accum = subexpression,
-                        _ => panic!("Unexpected input")
-                    }
+                    sum += evaluate_part2(subexpression);
                 }
                 x => panic!("Unexpected input from {}: {} | ignore: {}", s, x, ignore)
             }
         }
-        (accum, oper)
-    }).0;
-    debug_print!("| Res: {}\n",res);
+        (product, sum)
+    });
+    let res = product * sum;
+    trace!("| Res: {}",res);
     res
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::expressions::{AddNode, ValNode, MulNode, BinaryTree, BTNode, eval_helper, evaluate, get_subexpression, evaluate_part2};
-    use crate::expressions::Op::Val;
-    use std::str::FromStr;
+    use super::{add_node, val_node, mul_node, BinaryTree, BTNode, eval_helper, evaluate, get_subexpression, evaluate_part2, check};
+    use proptest::prelude::*;
+
+    /// Homework line as a tree: a digit or a parenthesised group of terms joined by `+` and `*`
+    #[derive(Debug, Clone)]
+    enum Term {
+        Digit(i32),
+        Group(Vec<Term>, Vec<char>),
+    }
+
+    impl Term {
+        fn render(&self) -> String {
+            match self {
+                Term::Digit(digit) => digit.to_string(),
+                Term::Group(terms, operators) => format!("({})", Term::join(terms, operators)),
+            }
+        }
+        /// Terms without the outer parentheses
+        fn join(terms: &[Term], operators: &[char]) -> String {
+            let mut joined = terms[0].render();
+            operators.iter().zip(&terms[1..]).for_each(|(operator, term)| joined.push_str(&format!(" {} {}", operator, term.render())));
+            joined
+        }
+        /// Everything evaluated left to right
+        fn left_to_right(&self) -> i64 {
+            match self {
+                Term::Digit(digit) => *digit as i64,
+                Term::Group(terms, operators) => operators.iter().zip(&terms[1..])
+                    .fold(terms[0].left_to_right(), |value, (operator, term)| match operator {
+                        '+' => value + term.left_to_right(),
+                        _ => value * term.left_to_right(),
+                    }),
+            }
+        }
+        /// Sums first, then the product of the sums
+        fn additions_first(&self) -> i64 {
+            match self {
+                Term::Digit(digit) => *digit as i64,
+                Term::Group(terms, operators) => {
+                    let mut sums = vec![terms[0].additions_first()];
+                    operators.iter().zip(&terms[1..]).for_each(|(operator, term)| match operator {
+                        '+' => *sums.last_mut().unwrap() += term.additions_first(),
+                        _ => sums.push(term.additions_first()),
+                    });
+                    sums.iter().product()
+                }
+            }
+        }
+        fn digits(&self) -> usize {
+            match self {
+                Term::Digit(_) => 1,
+                Term::Group(terms, _) => terms.iter().map(Term::digits).sum(),
+            }
+        }
+        /// Left leaning tree, the shape `evaluate` works through
+        fn tree(&self) -> BTNode<i32> {
+            match self {
+                Term::Digit(digit) => val_node(*digit),
+                Term::Group(terms, operators) => operators.iter().zip(&terms[1..])
+                    .fold(terms[0].tree(), |tree, (operator, term)| match operator {
+                        '+' => add_node(tree, term.tree()),
+                        _ => mul_node(tree, term.tree()),
+                    }),
+            }
+        }
+    }
+
+    fn group(term: impl Strategy<Value=Term> + Clone) -> impl Strategy<Value=Term> {
+        proptest::collection::vec(term, 2..=3).prop_flat_map(|terms| {
+            let operators = proptest::collection::vec(prop_oneof![Just('+'), Just('*')], terms.len() - 1);
+            operators.prop_map(move |operators| Term::Group(terms.clone(), operators))
+        })
+    }
+
+    /// Whole line, without parentheses around it. Nine digits at most keep every result within the `i32` of `BinaryTree`
+    fn line(nested: bool) -> impl Strategy<Value=Term> {
+        let digit = (1..=9).prop_map(Term::Digit);
+        match nested {
+            true => group(digit.prop_recursive(2, 9, 3, |term| group(term).boxed()).boxed())
+                .prop_filter("at most nine digits", |line| line.digits() <= 9).boxed(),
+            false => group(digit.boxed()).boxed(),
+        }
+    }
+
+    fn text(line: &Term) -> String {
+        match line {
+            Term::Group(terms, operators) => Term::join(terms, operators),
+            digit => digit.render(),
+        }
+    }
 
     #[test]
     fn from_example() {
-        let input = "1 + 2 * 3 + 4 * 5 + 6";
+        let _input = "1 + 2 * 3 + 4 * 5 + 6";
         let bt = BinaryTree::new(
-            AddNode(
-                MulNode(
-                    AddNode(
-                        MulNode(
-                            AddNode(
-                                ValNode(1),
-                                ValNode(2)),
-                            ValNode(3),
+            add_node(
+                mul_node(
+                    add_node(
+                        mul_node(
+                            add_node(
+                                val_node(1),
+                                val_node(2)),
+                            val_node(3),
                         ),
-                        ValNode(4),
+                        val_node(4),
                     ),
-                    ValNode(5),
+                    val_node(5),
                 ),
-                ValNode(6),
+                val_node(6),
             )
         );
-        assert_eq!(BinaryTree::collapse(&Box::new(bt.head.expect("aaaa"))), 71)
+        assert_eq!(BinaryTree::collapse(&bt.head.expect("aaaa")), 71)
     }
 
     #[test]
     fn from_str() {
-        assert_eq!(AddNode(ValNode(1), ValNode(2)), AddNode(ValNode(1), ValNode(2)));
-        // assert_eq!(BTNode::from_str("1 + 2").unwrap(), AddNode(ValNode(1), ValNode(2)));
+        assert_eq!(add_node(val_node(1), val_node(2)), add_node(val_node(1), val_node(2)));
+        // assert_eq!(BTNode::from_str("1 + 2").unwrap(), add_node(val_node(1), val_node(2)));
     }
 
     #[test]
@@ -294,4 +368,32 @@ mod tests {
         assert_eq!(evaluate_part2("5 + (8 * 3 + 9 + 3 * 4 * 3)"), 1445);
         assert_eq!(evaluate_part2("1 + (2 * 3) + (4 * (5 + 6))"), 51);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn malformed_expression() {
+        assert_eq!(check("1 + (2 * 3) + (4 * (5 + 6))"), Ok(()));
+        assert_eq!(check("1 + 23").unwrap_err().column, 6);
+        assert_eq!(check("1 + (2 * 3").unwrap_err().expected, "`)`");
+        assert_eq!(check("1 + 2)").unwrap_err().column, 6);
+        assert_eq!(check("1 - 2").unwrap_err().text, "-");
+        assert_eq!(check("1 +").unwrap_err().expected, "a digit or `(`");
+    }
+
+    proptest! {
+        #[test]
+        fn flat_evaluators_agree(line in line(false)) {
+            let text = text(&line);
+            prop_assert_eq!(eval_helper(&text) as i64, evaluate(&text));
+            prop_assert_eq!(evaluate(&text), line.left_to_right());
+        }
+
+        #[test]
+        fn evaluators_match_reference(line in line(true)) {
+            let text = text(&line);
+            prop_assert_eq!(check(&text), Ok(()));
+            prop_assert_eq!(evaluate(&text), line.left_to_right());
+            prop_assert_eq!(BinaryTree::collapse(&line.tree()) as i64, line.left_to_right());
+            prop_assert_eq!(evaluate_part2(&text), line.additions_first());
+        }
+    }
+}
@@ -0,0 +1,79 @@
+pub mod expressions;
+
+use crate::error::{ParseError, NoAnswer, parse_lines};
+use crate::solution::Solution;
+use crate::generate::Rng;
+use crate::repl::Session;
+use rand::Rng as _;
+use expressions::{check, evaluate, evaluate_part2};
+
+pub struct Day18;
+
+impl Day18 {
+    /// Random homework line, `digits` caps how many single digit numbers it uses so results stay within `i64`
+    fn expression(rng: &mut Rng, depth: usize, digits: &mut usize) -> String {
+        let terms = rng.gen_range(2..=4);
+        let mut expression = String::new();
+        for term in 0..terms {
+            if term > 0 {
+                expression.push_str(if rng.gen_bool(0.5) { " + " } else { " * " });
+            }
+            match depth < 2 && *digits > 4 && rng.gen_bool(0.3) {
+                true => expression.push_str(&format!("({})", Day18::expression(rng, depth + 1, digits))),
+                false => {
+                    *digits = digits.saturating_sub(1);
+                    expression.push_str(&rng.gen_range(1..=9).to_string());
+                }
+            }
+        }
+        expression
+    }
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const INPUT: &'static str = "./day-eighteen/expressions.dat";
+    const REVISION: u32 = 2;
+    type Input = Vec<String>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse_lines(input, |line| check(line).map(|_| line.to_string()))
+    }
+    fn part_one(homework: &Vec<String>) -> Result<i64, NoAnswer> {
+        Ok(homework.iter().map(|line| evaluate(line)).sum())
+    }
+    fn part_two(homework: &Vec<String>) -> Result<i64, NoAnswer> {
+        Ok(homework.iter().map(|line| evaluate_part2(line)).sum())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1)).map(|_| Day18::expression(rng, 0, &mut 10)).collect::<Vec<_>>().join("\n")
+    }
+}
+
+/// Homework done a line at a time, both ways
+#[derive(Default)]
+pub struct Homework {
+    lines: usize,
+    sums: (i64, i64),
+    last: Option<(i64, i64)>,
+}
+
+impl Session for Homework {
+    fn eval(&mut self, line: &str) -> Result<String, ParseError> {
+        check(line)?;
+        let results = (evaluate(line), evaluate_part2(line));
+        self.lines += 1;
+        self.sums = (self.sums.0 + results.0, self.sums.1 + results.1);
+        self.last = Some(results);
+        Ok(format!("{} left to right, {} with addition first", results.0, results.1))
+    }
+    fn state(&self) -> String {
+        let mut state = format!("{} lines, sums {} left to right, {} with addition first", self.lines, self.sums.0, self.sums.1);
+        if let Some((left_to_right, additions_first)) = self.last {
+            state.push_str(&format!("\nlast line {} left to right, {} with addition first", left_to_right, additions_first));
+        }
+        state
+    }
+}
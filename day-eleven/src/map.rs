@@ -1,10 +1,6 @@
-use crate::seat::*;
-use crate::coordinates::*;
-use itertools::Itertools;
-use core::ops;
-use std::ops::DerefMut;
-use std::fmt::{Formatter, Debug};
-use std::fmt;
+use std::iter;
+use super::seat::*;
+use crate::grid::{Grid, Point, Direction, ALL_AROUND};
 
 pub trait TaskLogic {
     fn should_stop(iter: usize) -> bool;
@@ -26,9 +22,9 @@ impl TaskLogic for PartOneLogic {
     }
 }
 
-pub struct partTwoLogic {}
+pub struct PartTwoLogic {}
 
-impl TaskLogic for partTwoLogic {
+impl TaskLogic for PartTwoLogic {
     fn should_stop(_: usize) -> bool {
         false
     }
@@ -41,41 +37,12 @@ impl TaskLogic for partTwoLogic {
     }
 }
 
-#[derive(PartialEq, Clone)]
-pub struct Map(pub Vec<Vec<Seat>>);
+/// Seat layout of the waiting area
+pub type Map = Grid<Seat>;
 
-impl Map where {
-    pub fn from_lines<'a, LineIterator>(iter: LineIterator) -> Map where LineIterator: IntoIterator<Item=&'a str> {
-        Map(iter.into_iter().map(|line| seats_from_line(line)).collect_vec())
-    }
-    pub fn at(&self, coord: &Coordinates) -> Option<Seat> {
-        let x_range = 0..self.len();
-        let y_range = 0..self.first().unwrap().len();
-        if x_range.contains(&coord.x) && y_range.contains(&coord.y) {
-            Some(self[coord.x][coord.y])
-        } else {
-            None
-        }
-    }
-}
-
-impl ops::Deref for Map {
-    type Target = Vec<Vec<Seat>>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl DerefMut for Map {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
-}
-
-pub fn update<I: TaskLogic>(map: &Map, x: usize, y: usize) -> Option<Seat> {
-    let occupied_neighbours = occupied_neighbours_part_2::<I>(map, x, y);
-    match map[x][y] {
+pub fn update<I: TaskLogic>(map: &Map, point: Point) -> Option<Seat> {
+    let occupied_neighbours = occupied_neighbours_part_2::<I>(map, point);
+    match map[point] {
         Seat::Empty {} => {
             if occupied_neighbours == 0 {
                 Some(Seat::Occupied {})
@@ -96,82 +63,84 @@ pub fn update<I: TaskLogic>(map: &Map, x: usize, y: usize) -> Option<Seat> {
 
 pub fn update_map<I: TaskLogic>(map: &Map) -> Map {
     let mut updated = map.clone();
-    for x in 0..map.len() {
-        for y in 0..map.first().unwrap().len() {
-            match map[x][y] {
-                Seat::Floor {} => {}
-                Seat::Empty {} | Seat::Occupied {} => {
-                    match update::<I>(&map, x, y) {
-                        Some(seat) => updated[x][y] = seat,
-                        None => {}
-                    }
-                }
+    for (point, seat) in map.iter() {
+        match seat {
+            Seat::Floor {} => {}
+            Seat::Empty {} | Seat::Occupied {} => {
+                if let Some(seat) = update::<I>(map, point) { updated[point] = seat }
             }
         }
     }
     updated
 }
 
-impl Debug for Map {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "Map : \n{}", self.iter().map(|row|
-            row.iter().map(|seat|
-                match seat {
-                    Seat::Floor {} => ".",
-                    Seat::Empty {} => "L",
-                    Seat::Occupied {} => "#"
-                }
-            ).join("")
-        ).join("\n"))
-    }
+/// The map after every round, starting with `map` itself and ending when a round changes nothing
+pub fn generations<I: TaskLogic>(map: &Map) -> impl Iterator<Item=Map> {
+    iter::successors(Some(map.clone()), |map| Some(update_map::<I>(map)).filter(|next| next != map))
 }
 
-pub(crate) fn occupied_seats(map: &Map) -> usize {
+pub fn stable_state<I: TaskLogic>(map: &Map) -> Map {
+    generations::<I>(map).last().expect("at least the map itself")
+}
+
+pub fn occupied_seats(map: &Map) -> usize {
     map.iter()
-        .map(|row| {
-            row.iter()
-                .filter_map(|seat| match seat {
-                    Seat::Occupied {} => Some(seat),
-                    _ => None
-                }).count()
-        })
-        .sum()
+        .filter(|(_, seat)| matches!(seat, Seat::Occupied {}))
+        .count()
 }
 
-fn check_direction<I: TaskLogic>(map: &Map, start: Coordinates, dir: Direction) -> usize {
-    let mut coord = start;
-    let mut iter = 0;
-    loop {
-        iter += 1;
-        coord += dir;
-        match map.at(&coord) {
-            Some(seat) => match seat {
-                Seat::Occupied {} => return 1,
-                Seat::Empty {} => return 0,
-                _ => {
-                    if I::should_stop(iter) { return 0; } else { continue; }
-                }
-            },
-            None => return 0
-        };
+fn check_direction<I: TaskLogic>(map: &Map, start: Point, dir: Direction) -> usize {
+    for (iter, point) in map.ray(start, dir).enumerate() {
+        match map[point] {
+            Seat::Occupied {} => return 1,
+            Seat::Empty {} => return 0,
+            Seat::Floor {} => if I::should_stop(iter + 1) { return 0; }
+        }
     }
+    0
 }
 
-fn occupied_neighbours_part_2<I: TaskLogic>(map: &Map, x: usize, y: usize) -> usize {
-    let directions = vec![
-        Direction(-1, -1), Direction(-1, 0), Direction(-1, 1),
-        Direction(-0, -1), Direction(0, 1),
-        Direction(1, -1), Direction(1, 0), Direction(1, 1),
-    ];
-    let start = Coordinates { x, y };
-    directions.iter().map(|&dir| check_direction::<I>(map, start, dir)).sum()
+fn occupied_neighbours_part_2<I: TaskLogic>(map: &Map, point: Point) -> usize {
+    ALL_AROUND.iter().map(|&dir| check_direction::<I>(map, point, dir)).sum()
 }
 
 #[cfg(test)]
 mod test {
-    use crate::map::{update_map, occupied_neighbours_part_2, Map, PartOneLogic, partTwoLogic, occupied_seats};
-    use crate::coordinates::{Coordinates, Direction};
-    use crate::seat::Seat;
+    use super::{update_map, occupied_neighbours_part_2, Map, PartOneLogic, PartTwoLogic, occupied_seats, generations};
+    use crate::day11::seat::Seat;
+    use crate::grid::Grid;
+    use std::str::FromStr;
+    use proptest::prelude::*;
+
+    /// Rectangular map of up to 8 by 8, made of the given kinds of squares
+    fn map(squares: Vec<Seat>) -> impl Strategy<Value=Map> {
+        (1..=8usize, 1..=8usize).prop_flat_map(move |(width, height)| {
+            proptest::collection::vec(proptest::sample::select(squares.clone()), width * height)
+                .prop_map(move |squares| Grid::from_rows(width, squares.chunks(width).map(<[Seat]>::to_vec).collect()))
+        })
+    }
+
+    /// Occupied seats touching `point`, counted the plain way
+    fn adjacent_occupied(map: &Map, point: (usize, usize)) -> usize {
+        map.neighbours8(point).filter(|&neighbour| map[neighbour] == Seat::Occupied {}).count()
+    }
+
+    proptest! {
+        #[test]
+        fn part_one_looks_at_adjacent_seats(map in map(vec![Seat::Floor {}, Seat::Empty {}, Seat::Occupied {}])) {
+            for point in map.points() {
+                prop_assert_eq!(occupied_neighbours_part_2::<PartOneLogic>(&map, point), adjacent_occupied(&map, point), "{:?}", point);
+            }
+        }
+
+        #[test]
+        fn without_floor_both_parts_see_the_same(map in map(vec![Seat::Empty {}, Seat::Occupied {}])) {
+            for point in map.points() {
+                prop_assert_eq!(occupied_neighbours_part_2::<PartTwoLogic>(&map, point),
+                                occupied_neighbours_part_2::<PartOneLogic>(&map, point), "{:?}", point);
+            }
+        }
+    }
 
     #[test]
     fn read_map() {
@@ -185,12 +154,11 @@ L.LLLLL.LL
 LLLLLLLLLL
 L.LLLLLL.L
 L.LLLLL.LL"#;
-        // println!("{:?}",seats_from_line_iter(input.lines()));
-        let map = Map::from_lines(input.lines());
-        assert_eq!(occupied_neighbours_part_2::<PartOneLogic>(&map, 0, 0), 0);
-        assert_eq!(map.first().unwrap().len(), 10);
-        assert_eq!(map.len(), 10);
-        assert_eq!(Map::from_lines(input.lines()), Map::from_lines(input.lines()));
+        let map = Map::from_str(input).unwrap();
+        assert_eq!(occupied_neighbours_part_2::<PartOneLogic>(&map, (0, 0)), 0);
+        assert_eq!(map.width(), 10);
+        assert_eq!(map.height(), 10);
+        assert_eq!(Map::from_str(input).unwrap(), Map::from_str(input).unwrap());
         let first_iter = r#"#.##.##.##
 #######.##
 #.#.#..#..
@@ -201,9 +169,9 @@ L.LLLLL.LL"#;
 ##########
 #.######.#
 #.#####.##"#;
-        let updated_map = update_map::<PartOneLogic>(&Map::from_lines(input.lines()));
-        assert_eq!(occupied_neighbours_part_2::<PartOneLogic>(&updated_map, 0, 0), 2);
-        assert_eq!(updated_map, Map::from_lines(first_iter.lines()));
+        let updated_map = update_map::<PartOneLogic>(&Map::from_str(input).unwrap());
+        assert_eq!(occupied_neighbours_part_2::<PartOneLogic>(&updated_map, (0, 0)), 2);
+        assert_eq!(updated_map, Map::from_str(first_iter).unwrap());
 
         let second_iter = r#"#.LL.L#.##
 #LLLLLL.L#
@@ -215,8 +183,8 @@ L.L.L..L..
 #LLLLLLLL#
 #.LLLLLL.L
 #.#LLLL.##"#;
-        assert_eq!(update_map::<PartOneLogic>(&Map::from_lines(first_iter.lines())),
-                   Map::from_lines(second_iter.lines()));
+        assert_eq!(update_map::<PartOneLogic>(&Map::from_str(first_iter).unwrap()),
+                   Map::from_str(second_iter).unwrap());
         let mut live_map = updated_map;
         loop {
             let new_state = update_map::<PartOneLogic>(&live_map);
@@ -234,11 +202,11 @@ L.L.L..L..
         let empty_input = r#".............
 .L.L.#.#.#.#.
 ............."#;
-        let map = Map::from_lines(empty_input.lines());
-        assert_eq!(map.at(&Coordinates { x: 1, y: 1 }), Some(Seat::Empty {}));
-        assert_eq!(map.at(&(Coordinates { x: 1, y: 1 } + Direction(1, 0))), Some(Seat::Floor {}));
-        assert_eq!(occupied_neighbours_part_2::<partTwoLogic>(&map, 1, 1), 0);
-        // assert_eq!(occupied_neighbours_part_2(&map, 1, 3), 1);
+        let map = Map::from_str(empty_input).unwrap();
+        assert_eq!(map.get((1, 1)), Some(&Seat::Empty {}));
+        assert_eq!(map.step((1, 1), (0, 1)).map(|point| map[point]), Some(Seat::Floor {}));
+        assert_eq!(occupied_neighbours_part_2::<PartTwoLogic>(&map, (1, 1)), 0);
+        // assert_eq!(occupied_neighbours_part_2(&map, (3, 1)), 1);
     }
 
     #[test]
@@ -250,9 +218,9 @@ L.L.L..L..
 ##...##
 #.#.#.#
 .##.##."#;
-        let map = Map::from_lines(empty_input.lines());
-        assert_eq!(occupied_neighbours_part_2::<partTwoLogic>(&map, 3, 3), 0);
-        // assert_eq!(occupied_neighbours_part_2(&map, 2, 4), 1);
+        let map = Map::from_str(empty_input).unwrap();
+        assert_eq!(occupied_neighbours_part_2::<PartTwoLogic>(&map, (3, 3)), 0);
+        // assert_eq!(occupied_neighbours_part_2(&map, (4, 2)), 1);
     }
 
     #[test]
@@ -267,16 +235,25 @@ L.L.L..L..
 ##########
 #.######.#
 #.#####.##"#;
-        let mut live_map = Map::from_lines(first_iter.lines());
+        let mut live_map = Map::from_str(first_iter).unwrap();
         loop {
-            let new_state = update_map::<partTwoLogic>(&live_map);
+            let new_state = update_map::<PartTwoLogic>(&live_map);
             if new_state == live_map {
                 break;
             } else {
-                println!("{:?}", new_state);
                 live_map = new_state
             }
         }
         assert_eq!(occupied_seats(&live_map), 26);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn generations_until_stable() {
+        let map = Map::from_str("L.LL.LL.LL\nLLLLLLL.LL\nL.L.L..L..\nLLLL.LL.LL\nL.LL.LL.LL\nL.LLLLL.LL\n..L.L.....\nLLLLLLLLLL\nL.LLLLLL.L\nL.LLLLL.LL").unwrap();
+        let rounds = generations::<PartOneLogic>(&map).collect::<Vec<_>>();
+        assert_eq!((rounds.len(), occupied_seats(&rounds[5])), (6, 37));
+        assert_eq!(rounds[0], map);
+        let rounds = generations::<PartTwoLogic>(&map).collect::<Vec<_>>();
+        assert_eq!((rounds.len(), occupied_seats(&rounds[6])), (7, 26));
+    }
+}
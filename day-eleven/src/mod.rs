@@ -0,0 +1,46 @@
+pub mod seat;
+pub mod map;
+
+use std::str::FromStr;
+use crate::animate::Frame;
+use crate::error::{ParseError, NoAnswer};
+use crate::solution::Solution;
+use crate::generate::{self, Rng};
+use map::{Map, stable_state, occupied_seats, generations, PartOneLogic, PartTwoLogic};
+
+pub struct Day11;
+
+impl Day11 {
+    /// The seats before and after every round, until they settle
+    pub fn frames(map: &Map, part: u8) -> Vec<Frame> {
+        let rounds = match part {
+            1 => generations::<PartOneLogic>(map).collect::<Vec<_>>(),
+            _ => generations::<PartTwoLogic>(map).collect(),
+        };
+        rounds.iter().enumerate().map(|(round, map)| Frame {
+            title: format!("Day 11 part {}, round {}: {} occupied seats", part, round, occupied_seats(map)),
+            text: map.to_string(),
+        }).collect()
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT: &'static str = "./day-eleven/map.dat";
+    type Input = Map;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Map::from_str(input)
+    }
+    fn part_one(map: &Map) -> Result<usize, NoAnswer> {
+        Ok(occupied_seats(&stable_state::<PartOneLogic>(map)))
+    }
+    fn part_two(map: &Map) -> Result<usize, NoAnswer> {
+        Ok(occupied_seats(&stable_state::<PartTwoLogic>(map)))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::character_map(rng, size.max(1), size.max(1), ('L', 0.75), '.')
+    }
+}
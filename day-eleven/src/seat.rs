@@ -1,5 +1,6 @@
 use std::str::FromStr;
-use itertools::Itertools;
+use crate::error::ParseError;
+use crate::grid::{Grid, Tile};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Seat {
@@ -8,42 +9,44 @@ pub enum Seat {
     Occupied {},
 }
 
-#[derive(Debug, Clone, PartialOrd, PartialEq)]
-pub struct ParseSeatError;
+impl Tile for Seat {
+    const EXPECTED: &'static str = "`.`, `L` or `#`";
 
-impl From<char> for Seat {
-    fn from(c: char) -> Self {
+    fn from_char(c: char) -> Option<Seat> {
         match c {
-            '.' => Self::Floor {},
-            'L' => Self::Empty {},
-            '#' => Self::Occupied {},
-            _ => Self::Empty {}
+            '.' => Some(Self::Floor {}),
+            'L' => Some(Self::Empty {}),
+            '#' => Some(Self::Occupied {}),
+            _ => None
+        }
+    }
+    fn to_char(&self) -> char {
+        match self {
+            Seat::Floor {} => '.',
+            Seat::Empty {} => 'L',
+            Seat::Occupied {} => '#',
         }
     }
 }
 
 impl FromStr for Seat {
-    type Err = ParseSeatError;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.parse::<char>() {
-            Ok(c) => match c {
-                '.' => Ok(Self::Floor {}),
-                'L' => Ok(Self::Empty {}),
-                '#' => Ok(Self::Occupied {}),
-                _ => Err(Self::Err {})
-            }
-            Err(_) => Err(Self::Err {})
+            Ok(c) => Seat::from_char(c).ok_or_else(|| ParseError::new(s, s, Seat::EXPECTED)),
+            Err(_) => Err(ParseError::new(s, s, "a single seat"))
         }
     }
 }
 
-pub fn seats_from_line(s: &str) -> Vec<Seat> {
-    s.chars().into_iter().map(Seat::from).collect_vec()
+pub fn seats_from_line(s: &str) -> Result<Vec<Seat>, ParseError> {
+    Ok(Grid::<Seat>::from_str(s)?.row(0).to_vec())
 }
 
 #[cfg(test)]
 mod tests{
-    use crate::seat::{Seat, seats_from_line, ParseSeatError};
+    use super::{Seat, seats_from_line};
+    use crate::grid::Tile;
     use std::str::FromStr;
 
     #[test]
@@ -51,8 +54,9 @@ mod tests{
         assert_eq!(Seat::from_str(".").unwrap(), Seat::Floor {});
         assert_eq!(Seat::from_str("L").unwrap(), Seat::Empty {});
         assert_eq!(Seat::from_str("#").unwrap(), Seat::Occupied {});
-        assert_eq!(Seat::from_str("1").expect_err(""), ParseSeatError {});
+        assert_eq!(Seat::from_str("1").unwrap_err().expected, Seat::EXPECTED);
 
-        assert_eq!(seats_from_line("L.LL.LL.LL").len(), 10);
+        assert_eq!(seats_from_line("L.LL.LL.LL").unwrap().len(), 10);
+        assert_eq!(seats_from_line("L.LL.LX.LL").unwrap_err().column, 7);
     }
-}
\ No newline at end of file
+}
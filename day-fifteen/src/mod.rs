@@ -0,0 +1,33 @@
+pub mod numbers;
+
+use crate::error::{ParseError, NoAnswer, number};
+use crate::solution::Solution;
+use crate::generate::{self, Rng};
+use numbers::{NumberGenerator, spoken_number};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const INPUT: &'static str = "./day-fifteen/starting_numbers.dat";
+    const REVISION: u32 = 2;
+    type Input = Vec<u64>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        input.trim().split(',').map(|start| number(input, start.trim())).collect()
+    }
+    fn part_one(start: &Vec<u64>) -> Result<u64, NoAnswer> {
+        Ok(spoken_number(&mut NumberGenerator::from(start.as_slice()), 2020))
+    }
+    fn part_two(start: &Vec<u64>) -> Result<u64, NoAnswer> {
+        Ok(spoken_number(&mut NumberGenerator::from(start.as_slice()), 30000000))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Fewer starting numbers than turns of part one, so it has some speaking to do
+        let count = size.clamp(1, 2019);
+        let numbers = generate::distinct(rng, &(0..2 * count as u64).collect::<Vec<_>>(), count);
+        numbers.iter().map(u64::to_string).collect::<Vec<_>>().join(",")
+    }
+}
@@ -1,27 +1,31 @@
-use std::collections::HashMap;
-use itertools::__std_iter::FromIterator;
-use debug_print::debug_print;
+use tracing::trace;
+use crate::dense::DenseMap;
+
+/// Numbers spoken after a turn count is this large are rare, the dense array stops here
+const DENSE_LIMIT: usize = 1 << 26;
 
-struct SpokenAt {
-    prev: u64,
-    last: u64
-}
 pub struct NumberGenerator{
+    /// The starting numbers, spoken on the first turns
+    start: Vec<u64>,
     last_spoken: u64,
+    /// Turn of the next number
     index: u64,
-    numbers: HashMap<u64, (u64,u64)>
+    /// Turn each number was last spoken on, not counting the latest turn, 0 for numbers not spoken yet
+    numbers: DenseMap<u32>,
 }
 
 impl From<&[u64]> for NumberGenerator {
     fn from(initial: &[u64]) -> Self {
+        let mut numbers = DenseMap::new(DENSE_LIMIT);
+        let (&last_spoken, earlier) = initial.split_last().unwrap();
+        for (i, &num) in earlier.iter().enumerate() {
+            numbers.insert(num as usize, (i + 1) as u32);
+        }
         NumberGenerator{
-            last_spoken: *initial.last().unwrap(),
+            start: initial.to_vec(),
+            last_spoken,
             index: initial.len() as u64 +1,
-            numbers: HashMap::from_iter(
-                initial.iter().enumerate().map(|(i, &num)|
-                    (num,((i+1) as u64, 0))
-                )
-            )
+            numbers,
         }
     }
 }
@@ -29,62 +33,41 @@ impl Iterator for NumberGenerator{
     type Item = u64;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // let val = self.numbers.get(&self.last_spoken).cloned();
-        match self.numbers.get_mut(&self.last_spoken){
-            Some((previous,last )) if *last == 0=> {
-                debug_print!("Turn {}: {} starting number spoken at {} then {}", self.index, self.last_spoken, previous, last);
-                self.last_spoken = 0;
-                self.update_last_spoken();
-                debug_print!(" returning {}\n",self.last_spoken);
-                self.index += 1;
-                Some(self.last_spoken)
-            },
-            Some((previous,last ))=> {
-                debug_print!("Turn {}: {} number spoken at {} then {}", self.index, self.last_spoken, previous, last);
-                // self.numbers.insert(self.last_spoken,(last, self.index));
-                self.last_spoken = *last-*previous;
-                self.update_last_spoken();
-                self.index += 1;
-                debug_print!(" returning {}\n",self.last_spoken);
-                Some(self.last_spoken)
-            },
-            _ => unimplemented!()
-        }
+        let turn = self.index - 1;
+        let previous = self.numbers.insert(self.last_spoken as usize, turn as u32);
+        trace!("Turn {}: {} spoken at {} before", self.index, self.last_spoken, previous);
+        self.last_spoken = match previous {
+            0 => 0,
+            previous => turn - previous as u64,
+        };
+        self.index += 1;
+        Some(self.last_spoken)
     }
 }
 
-impl NumberGenerator {
-    fn update_last_spoken(&mut self) {
-        match self.numbers.get_mut(&self.last_spoken) {
-            Some(val) if val.1 == 0 => *val = (val.0, self.index),
-            Some(val) => *val = (val.1, self.index),
-            None => { self.numbers.insert(self.last_spoken, (self.index, 0)); }
-        }
-    }
-}
+/// Number spoken on turn `index`, counting from 1, which is either a starting number or still ahead of the generator
 pub fn spoken_number(generator: &mut NumberGenerator, index: usize) ->u64{
-    for x in 0..(index- generator.index as usize) { generator.next(); }
+    if let Some(&number) = generator.start.get(index - 1) {
+        return number;
+    }
+    for _x in 0..(index- generator.index as usize) { generator.next(); }
     generator.next().unwrap()
 }
 
 #[cfg(test)]
 mod tests{
-    use crate::numbers::{NumberGenerator, spoken_number};
+    use super::{NumberGenerator, spoken_number};
 
     #[test]
     fn ctor_test() {
         let generator = NumberGenerator::from(vec![0,3,6].as_slice());
-        // println!("{:#?}", generator.numbers);
         assert_eq!(generator.last_spoken, 6);
     }
     #[test]
     fn first_example_test() {
         let mut generator = NumberGenerator::from(vec![0,3,6].as_slice());
-        println!("{:?}", generator.numbers);
         assert_eq!(generator.next().unwrap(),0);
-        // println!("{:?}", generator.numbers);
         assert_eq!(generator.next().unwrap(),3);
-        // println!("{:?}", generator.numbers);
         assert_eq!(generator.next().unwrap(),3);
         assert_eq!(generator.next().unwrap(),1);
         assert_eq!(generator.next().unwrap(),0);
@@ -123,6 +106,17 @@ mod tests{
         }
     }
 
+    #[test]
+    fn get_2020th_number_from_starting_numbers() {
+        let start = (0..2500).collect::<Vec<u64>>();
+        let mut generator = NumberGenerator::from(start.as_slice());
+        assert_eq!(spoken_number(&mut generator, 2020), 2019);
+        let mut generator = NumberGenerator::from(&start[..2020]);
+        assert_eq!(spoken_number(&mut generator, 2020), 2019);
+        let mut generator = NumberGenerator::from(&start[..2019]);
+        assert_eq!(spoken_number(&mut generator, 2020), 0);
+    }
+
     #[test]
     fn get_2020th_number_from_puzzle_input() {
         {
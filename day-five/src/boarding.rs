@@ -0,0 +1,133 @@
+use tracing::trace;
+use crate::error::ParseError;
+
+
+#[derive(Debug)]
+struct Range {
+    min: usize,
+    max: usize,
+}
+
+impl Range {
+    pub fn lower(&self) -> Range {
+        let new_max = self.max - ((self.max as f64 - self.min as f64) / 2.0f64).ceil() as usize;
+        trace!("{:?} -lower-> {:?}", self, Range {
+            min: self.min,
+            max: new_max,
+        });
+        Range {
+            min: self.min,
+            max: new_max,
+        }
+    }
+    pub fn upper(&self) -> Range {
+        let new_min = self.min + ((self.max as f64 - self.min as f64) / 2.0f64).ceil() as usize;
+        trace!("{:?} -upper-> {:?}", self, Range {
+            min: new_min,
+            max: self.max,
+        });
+        Range {
+            min: new_min,
+            max: self.max,
+        }
+    }
+}
+
+pub fn row(str: &str) -> usize {
+    let mut range = Range { min: 0, max: 127 };
+    trace!("Finding row for {}", str);
+    str.chars().for_each(|c|
+        match c {
+            'F' => range = range.lower(),
+            'B' => range = range.upper(),
+            _ => panic!("Unexpected char: {}", c)
+        }
+    );
+    range.max
+}
+
+pub fn column(str: &str) -> usize {
+    let mut range = Range { min: 0, max: 7 };
+    trace!("Finding column for {}", str);
+    str.chars().for_each(|c|
+        match c {
+            'R' => range = range.upper(),
+            'L' => range = range.lower(),
+            _ => panic!("Unexpected char: {}", c)
+        }
+    );
+    range.max
+}
+
+pub fn seat_id(str: &str) -> Result<usize, ParseError> {
+    let unexpected = str.char_indices().find(|&(at, c)| match at {
+        0..=6 => c != 'F' && c != 'B',
+        7..=9 => c != 'L' && c != 'R',
+        _ => true
+    });
+    match unexpected {
+        Some((at, c)) => Err(ParseError::new(str, &str[at..at + c.len_utf8()], match at {
+            0..=6 => "`F` or `B`",
+            7..=9 => "`L` or `R`",
+            _ => "end of the boarding pass"
+        })),
+        None if str.len() < 10 => Err(ParseError::new(str, str, "a boarding pass of 10 letters")),
+        None => Ok(row(&str[0..=6]) * 8 + column(&str[7..=9]))
+    }
+}
+
+/// Inverse of `seat_id`, the row is spelled with `F`/`B` and the column with `L`/`R`
+pub fn boarding_pass(seat_id: usize) -> String {
+    let row = (0..7).rev().map(|bit| if seat_id >> (bit + 3) & 1 == 1 { 'B' } else { 'F' });
+    let column = (0..3).rev().map(|bit| if seat_id >> bit & 1 == 1 { 'R' } else { 'L' });
+    row.chain(column).collect()
+}
+
+pub fn part_one(seat_ids: &[usize]) -> Option<usize> {
+    seat_ids.iter().max().copied()
+}
+
+pub fn part_two(seat_ids: &[usize]) -> Option<usize> {
+    let mut seat_ids = seat_ids.to_vec();
+    seat_ids.sort_unstable();
+    seat_ids.windows(2)
+        .find(|pair| pair[1] - pair[0] != 1)
+        .map(|pair| pair[0] + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{row, column, seat_id, boarding_pass};
+
+    #[test]
+    fn row_test() {
+        assert_eq!(row("FBFBBFF"), 44);
+        assert_eq!(row("BFFFBBF"), 70);
+        assert_eq!(row("FFFBBBF"), 14);
+        assert_eq!(row("BBFFBBF"), 102);
+    }
+
+    #[test]
+    fn column_test() {
+        assert_eq!(column("RRR"), 7);
+        assert_eq!(column("RRR"), 7);
+        assert_eq!(column("RLL"), 4);
+    }
+
+    #[test]
+    fn seat_id_test() {
+        assert_eq!(seat_id("BFFFBBFRRR"), Ok(567));
+        assert_eq!(seat_id("FFFBBBFRRR"), Ok(119));
+        assert_eq!(seat_id("BBFFBBFRLL"), Ok(820));
+        assert_eq!(boarding_pass(567), "BFFFBBFRRR");
+        assert!((0..1024).all(|id| seat_id(&boarding_pass(id)) == Ok(id)));
+    }
+
+    #[test]
+    fn malformed_boarding_pass() {
+        assert_eq!(seat_id("BFFFBBFRXR").unwrap_err().column, 9);
+        assert_eq!(seat_id("BFFFRBFRRR").unwrap_err().expected, "`F` or `B`");
+        assert_eq!(seat_id("BFFFBBFRR").unwrap_err().expected, "a boarding pass of 10 letters");
+        assert_eq!(seat_id("BFFFBBFRRRR").unwrap_err().column, 11);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+pub mod boarding;
+
+use crate::error::{ParseError, NoAnswer, parse_lines};
+use crate::solution::Solution;
+use crate::generate::Rng;
+use rand::Rng as _;
+use rand::seq::SliceRandom;
+use boarding::{seat_id, boarding_pass};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = "./day-five/boarding_passes.dat";
+    const REVISION: u32 = 2;
+    type Input = Vec<usize>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        parse_lines(input, seat_id)
+    }
+    fn part_one(seat_ids: &Vec<usize>) -> Result<usize, NoAnswer> {
+        boarding::part_one(seat_ids).ok_or_else(|| NoAnswer::new("at least one boarding pass"))
+    }
+    fn part_two(seat_ids: &Vec<usize>) -> Result<usize, NoAnswer> {
+        boarding::part_two(seat_ids).ok_or_else(|| NoAnswer::new("a free seat between taken ones"))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Consecutive seats with a single free one among them, the plane has 1024 seats
+        let count = size.clamp(3, 1000);
+        let first = rng.gen_range(8..1024 - count);
+        let free = first + rng.gen_range(1..count - 1);
+        let mut seats = (first..first + count).filter(|&seat| seat != free).collect::<Vec<_>>();
+        seats.shuffle(rng);
+        seats.into_iter().map(boarding_pass).collect::<Vec<_>>().join("\n")
+    }
+}
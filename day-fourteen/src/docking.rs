@@ -2,16 +2,16 @@ use std::str::FromStr;
 use std::fmt::{Display, Formatter};
 use std::fmt;
 use std::ops::{BitOrAssign, BitAndAssign, BitAnd, BitOr};
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use either::*;
-use debug_print::{debug_print};
-
-extern crate regex;
+use tracing::trace;
 
+use lazy_static::lazy_static;
 use regex::Regex;
 use itertools::{Either, Itertools};
+use crate::error::{ParseError, number};
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Mask {
     ones: u64,
     zeroes: u64,
@@ -28,7 +28,7 @@ impl Mask {
         let masked = inst.addr.bitand(self.zeroes);
         let masked = masked.bitor(self.ones);
         let mut addresses = vec![masked];
-        debug_print!("{} => \n{:0>35b}\n", inst.addr, masked);
+        trace!("{} => {:0>35b}", inst.addr, masked);
         self.floating.iter()
             .for_each(
                 |index| {
@@ -36,33 +36,59 @@ impl Mask {
                 }
             );
         addresses.iter().for_each(|addr|
-            { debug_print!("{:0>35b}\n", addr); }
+            { trace!("{:0>35b}", addr); }
         );
         addresses
     }
 }
 
-impl FromStr for Mask {
-    type Err = ();
+impl Mask {
+    const BITS: usize = 36;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    /// The mask as written in a program, most significant bit first
+    pub fn bits(&self) -> String {
+        (0..Mask::BITS).rev().map(|bit| match (self.ones >> bit & 1, self.zeroes >> bit & 1) {
+            (1, _) => '1',
+            (_, 1) => '0',
+            _ => 'X',
+        }).collect()
+    }
+
+    /// How many floating bits, each one doubles the addresses a version 2 write goes to
+    pub fn floating(&self) -> usize {
+        self.floating.len()
+    }
+
+    /// Parses `s`, a slice of the instruction in `line`
+    fn parse(line: &str, s: &str) -> Result<Mask, ParseError> {
+        if s.len() != Mask::BITS {
+            return Err(ParseError::new(line, s, format!("a mask of {} bits", Mask::BITS)));
+        }
         let mut mask = Mask {
             ones: 0,
             zeroes: 0,
             floating: Vec::new(),
         };
-        s.chars().into_iter().rev().enumerate().for_each(|(iter, c)|
+        for (iter, (at, c)) in s.char_indices().rev().enumerate() {
             match c {
                 '1' => mask.ones += 1u64 << iter,
                 '0' => mask.zeroes += 1u64 << iter,
                 'X' => mask.floating.push(iter as u16),
-                _ => panic!("Unexpected char: {} at [{}] of {}", c, iter, s)
+                _ => return Err(ParseError::new(line, &s[at..at + c.len_utf8()], "`0`, `1` or `X`"))
             }
-        );
+        }
         Ok(mask)
     }
 }
 
+impl FromStr for Mask {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Mask::parse(s, s)
+    }
+}
+
 impl Display for Mask {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "Mask: \n[Ones:  {:b}\nZeroes: {:b}]", self.ones, self.zeroes)
@@ -83,115 +109,185 @@ impl Default for Interpreter {
     }
 }
 
-struct WriteInstruction {
+#[derive(Debug)]
+pub struct WriteInstruction {
     addr: u64,
     val: u64,
 }
 
 impl Interpreter {
-    pub fn read(&mut self, s: &str) -> Either<Mask, WriteInstruction> {
+    pub fn read(s: &str) -> Result<Either<Mask, WriteInstruction>, ParseError> {
         lazy_static! {
         //TODO consider MatchSet
-        static ref MASK_RE: Regex = Regex::new(r#"mask = (?P<mask>[01X]+)"#).unwrap();
-        static ref MEM_RE: Regex  = Regex::new(r#"mem\[(?P<address>\d+)\]\s=\s(?P<value>\w+)$"#).unwrap();
+        static ref MASK_RE: Regex = Regex::new(r#"^mask = (?P<mask>\S+)$"#).unwrap();
+        static ref MEM_RE: Regex  = Regex::new(r#"^mem\[(?P<address>\w+)\]\s=\s(?P<value>\w+)$"#).unwrap();
         }
-        match MASK_RE.captures(s) {
-            Some(matched) => {
-                let mask_str = matched.name("mask").unwrap().as_str();
-                debug_print!("{}\n", mask_str);
-                return Left(Mask::from_str(mask_str).unwrap());
-            }
-            None => {}
-        };
-        match MEM_RE.captures(s) {
-            Some(matched) => return Right(self.decode(matched)),
-            None => {}
+        if let Some(matched) = MASK_RE.captures(s) {
+            let mask_str = matched.name("mask").unwrap().as_str();
+            trace!("{}", mask_str);
+            return Mask::parse(s, mask_str).map(Left);
         };
-        Right(WriteInstruction { addr: 0, val: 0 })
+        if let Some(matched) = MEM_RE.captures(s) { return Interpreter::decode(s, matched).map(Right) };
+        Err(ParseError::new(s, s, "`mask = <bits>` or `mem[<address>] = <value>`"))
     }
 
-    fn decode(&mut self, matched: regex::Captures) -> WriteInstruction {
-        let addr = matched.name("address").unwrap().as_str().parse::<usize>().unwrap();
-        let val = matched.name("value").unwrap().as_str().parse::<u64>().unwrap();
-        debug_print!("{} => {}\n", addr, val);
-        WriteInstruction { addr: addr as u64, val }
+    fn decode(s: &str, matched: regex::Captures) -> Result<WriteInstruction, ParseError> {
+        // Addresses and values are 36 bits wide like the mask, wider ones would overflow the sum of the memory
+        let word = |text: &str| match number::<u64>(s, text)? {
+            word if word < 1 << Mask::BITS => Ok(word),
+            _ => Err(ParseError::new(s, text, format!("a number below 2^{}", Mask::BITS))),
+        };
+        let addr = word(matched.name("address").unwrap().as_str())?;
+        let val = word(matched.name("value").unwrap().as_str())?;
+        trace!("{} => {}", addr, val);
+        Ok(WriteInstruction { addr, val })
     }
 
     pub fn sum_values(&self) -> u64 {
-        self.memory.iter().map(|(key, val)| val).sum()
+        self.memory.values().sum()
+    }
+    pub fn mask(&self) -> &Mask {
+        &self.current_mask
+    }
+    pub fn memory(&self) -> &HashMap<u64, u64> {
+        &self.memory
     }
 }
 
 pub trait Decoder {
-    fn read(&mut self, s: &str);
+    fn apply(&mut self, instruction: &Either<Mask, WriteInstruction>);
+    fn read(&mut self, s: &str) -> Result<(), ParseError> {
+        self.apply(&Interpreter::read(s)?);
+        Ok(())
+    }
     fn sum_values(&self) -> u64;
+    fn interpreter(&self) -> &Interpreter;
 }
 
+#[derive(Default)]
 pub struct DecoderV1 {
     interpreter: Interpreter
 }
 
 impl Decoder for DecoderV1 {
-    fn read(&mut self, s: &str) {
-        let either = self.interpreter.read(s);
-        either.as_ref().left().map(|mask| self.interpreter.current_mask = mask.clone());
-        either.as_ref().right().map(|inst| { self.interpreter.memory.insert(inst.addr, self.interpreter.current_mask.apply(inst.val)); });
+    fn apply(&mut self, instruction: &Either<Mask, WriteInstruction>) {
+        match instruction {
+            Left(mask) => self.interpreter.current_mask = mask.clone(),
+            Right(inst) => { self.interpreter.memory.insert(inst.addr, self.interpreter.current_mask.apply(inst.val)); }
+        }
     }
 
     fn sum_values(&self) -> u64 {
         self.interpreter.sum_values()
     }
-}
-
-impl Default for DecoderV1 {
-    fn default() -> Self {
-        DecoderV1 {
-            interpreter: Default::default()
-        }
+    fn interpreter(&self) -> &Interpreter {
+        &self.interpreter
     }
 }
 
+
+#[derive(Default)]
 pub struct DecoderV2 {
     interpreter: Interpreter
 }
 
-impl Default for DecoderV2 {
-    fn default() -> Self {
-        DecoderV2 {
-            interpreter: Default::default()
-        }
-    }
-}
 
 impl Decoder for DecoderV2 {
-    fn read(&mut self, s: &str) {
-        let either = self.interpreter.read(s);
-        either.as_ref().left().map(|mask| self.interpreter.current_mask = mask.clone());
-        either.as_ref().right()
-            .map(|inst| {
-                self.interpreter.current_mask.all_addresses(&inst).iter().unique()
-                    .for_each(|&addr|
-                        { self.interpreter.memory.insert(addr, inst.val); }
-                    );
-            });
+    fn apply(&mut self, instruction: &Either<Mask, WriteInstruction>) {
+        match instruction {
+            Left(mask) => self.interpreter.current_mask = mask.clone(),
+            Right(inst) => self.interpreter.current_mask.all_addresses(inst).iter().unique()
+                .for_each(|&addr|
+                    { self.interpreter.memory.insert(addr, inst.val); }
+                ),
+        }
     }
     fn sum_values(&self) -> u64 {
         self.interpreter.sum_values()
     }
+    fn interpreter(&self) -> &Interpreter {
+        &self.interpreter
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::docking::{Mask, Interpreter, DecoderV1, Decoder, WriteInstruction, DecoderV2};
+    use super::{Mask, DecoderV1, Decoder, WriteInstruction, DecoderV2, Interpreter};
+    use crate::day14::Day14;
+    use crate::solution::Solution;
+    use itertools::Either;
     use std::str::FromStr;
+    use std::collections::HashMap;
+    use proptest::prelude::*;
+
+    /// Masks with at most six floating bits, each followed by a few writes to a small address space so they collide
+    fn program() -> impl Strategy<Value=Vec<(String, Vec<(u64, u64)>)>> {
+        let mask = proptest::collection::vec(prop_oneof![4 => Just('0'), 4 => Just('1'), 1 => Just('X')], 36)
+            .prop_filter("at most six floating bits", |bits| bits.iter().filter(|&&bit| bit == 'X').count() <= 6)
+            .prop_map(|bits| bits.into_iter().collect::<String>());
+        let writes = proptest::collection::vec((0..64u64, 0..1u64 << 36), 1..4);
+        proptest::collection::vec((mask, writes), 1..4)
+    }
+
+    fn run(mut decoder: impl Decoder, program: &[(String, Vec<(u64, u64)>)]) -> u64 {
+        for (mask, writes) in program {
+            decoder.read(&format!("mask = {}", mask)).unwrap();
+            writes.iter().for_each(|(address, value)| decoder.read(&format!("mem[{}] = {}", address, value)).unwrap());
+        }
+        decoder.sum_values()
+    }
+
+    /// Bit by bit reading of the puzzle text, `bit` counts from the right like the mask does
+    fn mask_bit(mask: &str, bit: usize) -> char {
+        mask.as_bytes()[35 - bit] as char
+    }
+
+    fn reference_v1(program: &[(String, Vec<(u64, u64)>)]) -> u64 {
+        let mut memory = HashMap::new();
+        for (mask, writes) in program {
+            for &(address, value) in writes {
+                let value = (0..36).fold(value, |value, bit| match mask_bit(mask, bit) {
+                    '0' => value & !(1 << bit),
+                    '1' => value | 1 << bit,
+                    _ => value,
+                });
+                memory.insert(address, value);
+            }
+        }
+        memory.values().sum()
+    }
+
+    fn reference_v2(program: &[(String, Vec<(u64, u64)>)]) -> u64 {
+        let mut memory = HashMap::new();
+        for (mask, writes) in program {
+            let floating = (0..36).filter(|&bit| mask_bit(mask, bit) == 'X').collect::<Vec<_>>();
+            for &(address, value) in writes {
+                let address = (0..36).fold(address, |address, bit| match mask_bit(mask, bit) {
+                    '1' => address | 1 << bit,
+                    _ => address,
+                });
+                // Every combination of the floating bits, counting through them like a binary number
+                for combination in 0..1u64 << floating.len() {
+                    let address = floating.iter().enumerate().fold(address, |address, (index, &bit)| {
+                        match combination >> index & 1 {
+                            1 => address | 1 << bit,
+                            _ => address & !(1 << bit),
+                        }
+                    });
+                    memory.insert(address, value);
+                }
+            }
+        }
+        memory.values().sum()
+    }
 
     #[test]
     fn mask_from_str() {
         let mask = Mask::from_str("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X").unwrap();
-        println!("{:}", mask);
         assert_eq!(mask.apply(11), 73);
         assert_eq!(mask.apply(101), 101);
         assert_eq!(mask.apply(0), 64);
+        assert_eq!(mask.bits(), "XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X");
     }
 
     #[test]
@@ -201,7 +297,7 @@ mem[8] = 11
 mem[7] = 101
 mem[8] = 0"#;
         let mut interpreter = DecoderV1::default();
-        input.lines().for_each(|line| interpreter.read(line));
+        input.lines().for_each(|line| interpreter.read(line).unwrap());
         assert_eq!(interpreter.sum_values(), 165)
     }
 
@@ -219,25 +315,65 @@ mem[53283] = 7137
 mem[57344] = 62358
 mem[63867] = 9443"#;
         let mut interpreter = DecoderV1::default();
-        input.lines().for_each(|line| interpreter.read(line))
+        input.lines().for_each(|line| interpreter.read(line).unwrap())
     }
 
     #[test]
     fn all_addresses_test() {
         let mask = Mask::from_str("000000000000000000000000000000X1001X").unwrap();
-        println!("{:?}", mask.floating);
+        assert_eq!(mask.floating, vec![0, 5]);
         let inst = WriteInstruction { addr: 42, val: 100 };
         assert_eq!(mask.all_addresses(&inst), vec![26, 27, 58, 59]);
     }
 
     #[test]
     fn one_segment_v2() {
-        let input = r#"mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
-mem[8] = 11
-mem[7] = 101
-mem[8] = 0"#;
+        let input = r#"mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1"#;
         let mut interpreter = DecoderV2::default();
-        input.lines().for_each(|line| interpreter.read(line));
+        input.lines().for_each(|line| interpreter.read(line).unwrap());
         assert_eq!(interpreter.sum_values(), 208)
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn part_one_answers_any_mask() {
+        let example = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem[8] = 11\nmem[7] = 101\nmem[8] = 0";
+        let program = Day14::parse(example).unwrap();
+        assert_eq!(Day14::part_one(&program).unwrap(), 165);
+        assert_eq!(Day14::part_two(&program).unwrap_err().expected,
+                   "masks with at most 16 floating bits for the version 2 decoder");
+
+        let harder = include_str!("../harder.dat");
+        let mut segments: Vec<(String, Vec<(u64, u64)>)> = Vec::new();
+        for line in harder.lines() {
+            match Interpreter::read(line).unwrap() {
+                Either::Left(mask) => segments.push((mask.bits(), Vec::new())),
+                Either::Right(write) => segments.last_mut().unwrap().1.push((write.addr, write.val)),
+            }
+        }
+        let program = Day14::parse(harder).unwrap();
+        assert_eq!(Day14::part_one(&program).unwrap(), reference_v1(&segments));
+        assert_eq!(Day14::part_one(&program).unwrap(), 2931263534737);
+    }
+
+    #[test]
+    fn malformed_instruction() {
+        assert_eq!(Interpreter::read("mask = 0000000000000000000000000000000000X2").unwrap_err().column, 43);
+        assert_eq!(Interpreter::read("mask = 0X").unwrap_err().expected, "a mask of 36 bits");
+        assert_eq!(Interpreter::read("mem[8] = eleven").unwrap_err().column, 10);
+        assert!(Interpreter::read("mem[8] == 11").is_err());
+        assert!(Interpreter::read("mem[8] = 68719476735").is_ok());
+        assert_eq!(Interpreter::read("mem[8] = 68719476736").unwrap_err().expected, "a number below 2^36");
+        assert_eq!(Interpreter::read("mem[68719476736] = 1").unwrap_err().column, 5);
+    }
+
+    proptest! {
+        #[test]
+        fn decoders_match_the_puzzle_text(program in program()) {
+            prop_assert_eq!(run(DecoderV1::default(), &program), reference_v1(&program));
+            prop_assert_eq!(run(DecoderV2::default(), &program), reference_v2(&program));
+        }
+    }
+}
@@ -0,0 +1,100 @@
+pub mod docking;
+
+use either::Either;
+use itertools::Itertools;
+use crate::error::{ParseError, NoAnswer, parse_lines};
+use crate::solution::Solution;
+use crate::generate::Rng;
+use crate::repl::Session;
+use rand::Rng as _;
+use docking::{Decoder, DecoderV1, DecoderV2, Interpreter, Mask, WriteInstruction};
+
+pub struct Day14;
+
+impl Day14 {
+    /// Masks with more floating bits are too many writes for the version 2 decoder to keep up, 2^36 at worst.
+    /// The version 1 decoder leaves floating bits alone, so any mask will do for it.
+    pub const FLOATING: usize = 16;
+
+    fn run(mut decoder: impl Decoder, program: &[Either<Mask, WriteInstruction>]) -> u64 {
+        program.iter().for_each(|instruction| decoder.apply(instruction));
+        decoder.sum_values()
+    }
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const INPUT: &'static str = "./day-fourteen/instructions.dat";
+    const REVISION: u32 = 2;
+    type Input = Vec<Either<Mask, WriteInstruction>>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Interpreter::read)
+    }
+    fn part_one(program: &Self::Input) -> Result<u64, NoAnswer> {
+        Ok(Day14::run(DecoderV1::default(), program))
+    }
+    fn part_two(program: &Self::Input) -> Result<u64, NoAnswer> {
+        match program.iter().filter_map(|instruction| instruction.as_ref().left()).any(|mask| mask.floating() > Day14::FLOATING) {
+            true => Err(NoAnswer::new(format!("masks with at most {} floating bits for the version 2 decoder", Day14::FLOATING))),
+            false => Ok(Day14::run(DecoderV2::default(), program)),
+        }
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut program = Vec::new();
+        while program.len() < size.max(2) {
+            // Few floating bits, every one of them doubles the writes of the version 2 decoder
+            let floating = rng.gen_range(0..=9);
+            let mut mask = (0..36).map(|_| if rng.gen_bool(0.5) { '1' } else { '0' }).collect::<Vec<_>>();
+            (0..floating).for_each(|_| mask[rng.gen_range(0..36)] = 'X');
+            program.push(format!("mask = {}", mask.into_iter().collect::<String>()));
+            for _ in 0..rng.gen_range(1..=5) {
+                program.push(format!("mem[{}] = {}", rng.gen_range(0..65536), rng.gen_range(0..1u64 << 36)));
+            }
+        }
+        program.join("\n")
+    }
+}
+
+/// Both decoders fed the same program a line at a time
+#[derive(Default)]
+pub struct Docking {
+    v1: DecoderV1,
+    v2: DecoderV2,
+}
+
+impl Session for Docking {
+    fn eval(&mut self, line: &str) -> Result<String, ParseError> {
+        let instruction = Interpreter::read(line)?;
+        self.v1.apply(&instruction);
+        let floating = self.v2.interpreter().mask().floating();
+        Ok(match instruction {
+            Either::Left(mask) => {
+                self.v2.apply(&Either::Left(mask));
+                format!("mask {}", self.v1.interpreter().mask().bits())
+            }
+            Either::Right(_) if floating > Day14::FLOATING =>
+                format!("sum {} (version 1), skipped version 2 with {} floating bits", self.v1.sum_values(), floating),
+            write => {
+                self.v2.apply(&write);
+                format!("sum {} (version 1), {} (version 2)", self.v1.sum_values(), self.v2.sum_values())
+            }
+        })
+    }
+    fn state(&self) -> String {
+        let mut state = format!("mask {}", self.v1.interpreter().mask().bits());
+        for (version, interpreter) in [(1, self.v1.interpreter()), (2, self.v2.interpreter())] {
+            let memory = interpreter.memory();
+            state.push_str(&format!("\nversion {}: {} addresses, sum {}", version, memory.len(), interpreter.sum_values()));
+            for (address, value) in memory.iter().sorted().take(32) {
+                state.push_str(&format!("\n  mem[{}] = {}", address, value));
+            }
+            if memory.len() > 32 {
+                state.push_str("\n  ...");
+            }
+        }
+        state
+    }
+}
@@ -0,0 +1,56 @@
+pub mod xmas;
+
+use crate::error::{ParseError, NoAnswer, parse_lines, number};
+use crate::solution::Solution;
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::generate::{self, Rng};
+use rand::Rng as _;
+use xmas::{first_invalid, encryption_weakness, PREAMBLE_SIZE};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = "./day-nine/code.dat";
+    const REVISION: u32 = 2;
+    type Input = Vec<u128>;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Vec<u128>, ParseError> {
+        parse_lines(input, |line| number(line, line.trim()))
+    }
+    fn part_one(data: &Vec<u128>) -> Result<u128, NoAnswer> {
+        first_invalid(data).ok_or_else(|| NoAnswer::new(format!("a number that isn't the sum of two of the {} before it", PREAMBLE_SIZE)))
+    }
+    fn part_two(data: &Vec<u128>) -> Result<u128, NoAnswer> {
+        let invalid = Day9::part_one(data)?;
+        encryption_weakness(data, invalid).ok_or_else(|| NoAnswer::new(format!("a contiguous set of numbers that sums to {}", invalid)))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let length = size.clamp(PREAMBLE_SIZE + 1, 2000);
+        let invalid_at = PREAMBLE_SIZE.max(length * 3 / 4);
+        let mut stream = generate::distinct(rng, &(1..=2 * PREAMBLE_SIZE as u128).collect::<Vec<_>>(), PREAMBLE_SIZE);
+        while stream.len() < length {
+            let window = &stream[stream.len() - PREAMBLE_SIZE..];
+            let sums = window.iter().tuple_combinations().filter(|(left, right)| left != right).map(|(left, right)| left + right).collect::<HashSet<_>>();
+            let next = match stream.len() == invalid_at {
+                // Has to be a contiguous run of numbers, the sum of everything so far is too big to be a sum of two
+                true => (0..20).map(|_| {
+                    let start = rng.gen_range(0..stream.len() - 1);
+                    let end = rng.gen_range(start + 2..=stream.len());
+                    stream[start..end].iter().sum::<u128>()
+                }).find(|sum| !sums.contains(sum)).unwrap_or_else(|| stream.iter().sum()),
+                // Summing the smallest numbers keeps the stream from growing too fast
+                false => {
+                    let smallest = window.iter().copied().sorted().dedup().take(5).collect::<Vec<_>>();
+                    let pair = generate::distinct(rng, &smallest, 2);
+                    pair[0] + pair[1]
+                }
+            };
+            stream.push(next);
+        }
+        stream.iter().map(u128::to_string).collect::<Vec<_>>().join("\n")
+    }
+}
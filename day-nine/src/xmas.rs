@@ -0,0 +1,131 @@
+use tracing::trace;
+
+use std::collections::VecDeque;
+use itertools::Itertools;
+use itertools::MinMaxResult::MinMax;
+use num::{Num,  Integer};
+use std::fmt::{Debug, Display};
+use std::ops::{AddAssign, SubAssign};
+use itertools::__std_iter::Sum;
+use prefix_sum::summable::Summable;
+pub const PREAMBLE_SIZE: usize = 45;
+
+pub struct Decypher<I> where I: Num {
+    pub received: VecDeque<I>
+}
+
+impl<I> Decypher<I> where I: Copy + Display + Debug + Integer + Eq + Summable + Sum + AddAssign + SubAssign {
+    pub fn from_preamble(preamble: &[I]) -> Decypher<I> {
+        Decypher {
+            received: preamble.iter().copied().collect()
+        }
+    }
+    pub fn push(&mut self, item: I) -> bool {
+        trace!("Matching {} against {:?}", item, &self.received);
+        let test = self.received.iter().copied()
+            .collect::<Vec<I>>()
+            .into_iter()
+            .permutations(2)
+            .map(|vec| (vec[0], vec[1]))
+            .filter(|(left, right)| left != right)
+            .filter(|(left, right)| *left + *right == item)
+            .map(|tup| {
+                trace!("{:?} => {}", tup, item);
+                tup
+            })
+            .any(|(left, right)| left + right == item);
+        if test {
+            self.received.pop_front();
+            self.received.push_back(item);
+        }
+        test
+    }
+    pub fn contigous_set(&mut self, item: I) -> Vec<I> {
+        let data = self.received.iter().copied().collect_vec();
+        trace!("Data vec {:?}\t Item: {}",data,item);
+        for start in 0..self.received.len() {
+            for end in start + 2..=self.received.len() {
+                match check(&data[start..end], item) {
+                    Ok(res) => return res,
+                    Err(_) => continue
+                }
+            }
+        }
+        Vec::new()
+    }
+    pub fn contigous(self, data: &[I], item: I) -> Vec<I> {
+        trace!("Data vec {:?}\t Item: {}",data,item);
+        for start in 0..data.len() {
+            for end in start + 2..=data.len() {
+                match check(&data[start..end], item) {
+                    Ok(res) => return res,
+                    Err(_) => continue
+                }
+            }
+        }
+        Vec::new()
+    }
+}
+
+pub fn first_invalid<I>(data: &[I]) -> Option<I>
+    where I: Copy + Display + Debug + Integer + Summable + Sum + AddAssign + SubAssign {
+    let (preamble, rest) = data.split_at(PREAMBLE_SIZE.min(data.len()));
+    let mut decypher = Decypher::from_preamble(preamble);
+    rest.iter().find(|&&num| !decypher.push(num)).copied()
+}
+
+pub fn encryption_weakness<I>(data: &[I], invalid: I) -> Option<I>
+    where I: Copy + Display + Debug + Integer + Summable + Sum + AddAssign + SubAssign {
+    match Decypher::from_preamble(&[]).contigous(data, invalid).iter().minmax() {
+        MinMax(&min, &max) => Some(min + max),
+        _ => None
+    }
+}
+
+pub fn check<Int>(slice: &[Int], item: Int) -> Result<Vec<Int>, bool>
+    where Int: Summable + Copy + Sum + Display + Debug + std::cmp::PartialEq
+{
+    match slice.iter().copied().sum::<Int>() {
+        x if x == item => {
+            let vec = slice.iter().copied().collect_vec();
+            trace!("Found match Sum({:?}) == {}",vec,item);
+            Ok(vec)
+        }
+        x => {
+            trace!("Sum{:?}: {} != {}",slice,x,item);
+            Err(false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod xmas_code_tests {
+    use super::Decypher;
+
+    #[test]
+    fn from_example_5() {
+        let preamble = vec![35, 20, 15, 25, 47, 40];
+        let numbers = vec![62, 55, 65, 95, 102, 117, 150, 182, 127, 219];
+        let mut decypher = Decypher::from_preamble(&preamble);
+        numbers.into_iter().for_each(|num|
+            match num {
+                127 => assert!(!decypher.push(num), "{} accepted, but it shouldn't", num),
+                _ => assert!(decypher.push(num), "{} not accepted, but it should", num)
+            });
+        assert_ne!(decypher.contigous_set(127), preamble);
+        let mut decypher = Decypher::from_preamble(&preamble);
+        assert_eq!(decypher.contigous_set(127), vec![15, 25, 47, 40]);
+    }
+
+    #[test]
+    fn from_example_25() {
+        let preamble: Vec<i32> = (0..=25).into_iter().collect();
+        let numbers = vec![100, 50, 26, 49]; //order is important
+        let mut decypher = Decypher::from_preamble(&preamble);
+        numbers.into_iter().for_each(|num|
+            match num {
+                0..=49 => assert!(decypher.push(num), "{} not accepted, but it should", num),
+                _ => assert!(!decypher.push(num), "{} accepted, but it shouldn't", num)
+            });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+pub mod rules;
+
+use std::str::FromStr;
+use crate::error::{ParseError, NoAnswer};
+use crate::solution::Solution;
+use crate::generate::Rng;
+use rand::Rng as _;
+use rand::seq::SliceRandom;
+use rules::Messages;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    /// The puzzle's part two example, it has an answer for both parts
+    const INPUT: &'static str = "./day-nineteen/messages.dat";
+    type Input = Messages;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Messages, ParseError> {
+        Messages::from_str(input)
+    }
+    fn part_one(messages: &Messages) -> Result<usize, NoAnswer> {
+        Ok(messages.count_matching(&messages.grammar))
+    }
+    fn part_two(messages: &Messages) -> Result<usize, NoAnswer> {
+        Ok(messages.count_matching(&messages.grammar.with_loops()))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Like the puzzle, rule 0 is `8 11` and rules 42 and 31 split the three letter words of `a` and `b`
+        // between them, so part two matches some messages of 42s followed by fewer 31s that part one doesn't
+        let mut words = (0..8).map(|bits: u8| (0..3).map(|bit| if bits >> bit & 1 == 0 { 'a' } else { 'b' }).collect::<String>())
+            .collect::<Vec<_>>();
+        words.shuffle(rng);
+        let (forty_two, thirty_one) = words.split_at(rng.gen_range(1..8));
+        let alternatives = |words: &[String]| words.iter()
+            .map(|word| word.chars().map(|c| if c == 'a' { "1" } else { "14" }).collect::<Vec<_>>().join(" "))
+            .collect::<Vec<_>>().join(" | ");
+        let mut rules = ["0: 8 11".to_string(), "8: 42".to_string(), "11: 42 31".to_string(),
+                         format!("42: {}", alternatives(forty_two)), format!("31: {}", alternatives(thirty_one)),
+                         "1: \"a\"".to_string(), "14: \"b\"".to_string()];
+        rules.shuffle(rng);
+        let messages = (0..size.max(1)).map(|_| match rng.gen_bool(0.6) {
+            true => {
+                let thirty_ones = rng.gen_range(1..=2);
+                let forty_twos = rng.gen_range(thirty_ones + 1..=thirty_ones + 2);
+                let mut message = String::new();
+                (0..forty_twos).for_each(|_| message.push_str(forty_two.choose(rng).unwrap()));
+                (0..thirty_ones).for_each(|_| message.push_str(thirty_one.choose(rng).unwrap()));
+                message
+            }
+            false => (0..rng.gen_range(3..=15)).map(|_| if rng.gen_bool(0.5) { 'a' } else { 'b' }).collect(),
+        }).collect::<Vec<_>>();
+        format!("{}\n\n{}", rules.join("\n"), messages.join("\n"))
+    }
+}
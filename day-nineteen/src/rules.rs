@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use lazy_static::lazy_static;
+use regex::Regex;
+use crate::error::{ParseError, number};
+
+/// Right hand side of a numbered rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    /// A single character, `"a"`
+    Literal(char),
+    /// Sequences of other rules, `1 2 | 2 1`, any one of them matches
+    Alternatives(Vec<Vec<usize>>),
+}
+
+impl Rule {
+    /// Parses `body`, a slice of `source`, errors point into `source`
+    fn parse(source: &str, body: &str) -> Result<Rule, ParseError> {
+        lazy_static! {
+            static ref LITERAL_RE: Regex = Regex::new(r#"^"(?P<char>[^"])"$"#).unwrap();
+        }
+        if let Some(matched) = LITERAL_RE.captures(body) {
+            return Ok(Rule::Literal(matched["char"].chars().next().unwrap()));
+        }
+        if body.starts_with('"') {
+            return Err(ParseError::new(source, body, "a single quoted character like `\"a\"`"));
+        }
+        body.split('|').map(|sequence| {
+            let rules = sequence.split_whitespace().map(|rule| number(source, rule)).collect::<Result<Vec<usize>, _>>()?;
+            match rules.is_empty() {
+                true => Err(ParseError::new(source, sequence, "rule numbers or a quoted character")),
+                false => Ok(rules),
+            }
+        }).collect::<Result<_, _>>().map(Rule::Alternatives)
+    }
+}
+
+/// Numbered rules, a message is valid when rule 0 matches all of it
+#[derive(Debug, Clone)]
+pub struct Grammar {
+    rules: HashMap<usize, Rule>,
+}
+
+impl FromStr for Grammar {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RULE_RE: Regex = Regex::new(r#"^(?P<id>\d+):\s*(?P<body>.*?)\s*$"#).unwrap();
+        }
+        let mut rules = HashMap::new();
+        let mut lines = Vec::new();
+        for line in s.lines().filter(|line| !line.trim().is_empty()) {
+            let matched = RULE_RE.captures(line)
+                .ok_or_else(|| ParseError::new(s, line, "a rule like `0: 4 1 5` or `4: \"a\"`"))?;
+            let id = matched.name("id").unwrap().as_str();
+            let rule = Rule::parse(s, matched.name("body").unwrap().as_str())?;
+            if rules.insert(number(s, id)?, rule).is_some() {
+                return Err(ParseError::new(s, id, "a rule number not used before"));
+            }
+            lines.push(line);
+        }
+        for line in lines {
+            let id = number(s, &line[..line.find(':').unwrap()])?;
+            if let Rule::Alternatives(alternatives) = &rules[&id] {
+                if let Some(missing) = alternatives.iter().flatten().find(|&rule| !rules.contains_key(rule)) {
+                    return Err(ParseError::new(s, line, format!("rules that are all defined, there's no rule {}", missing)));
+                }
+            }
+        }
+        match rules.contains_key(&0) {
+            true => Ok(Grammar { rules }),
+            false => Err(ParseError::new(s, &s[s.len()..], "a rule 0")),
+        }
+    }
+}
+
+impl Grammar {
+    /// Whether rule 0 matches all of `message`
+    pub fn matches(&self, message: &str) -> bool {
+        self.ends(0, message, 0, &mut HashMap::new()).contains(&message.len())
+    }
+
+    /// The same rules with 8 and 11 replaced by `42 | 42 8` and `42 31 | 42 11 31`, which repeat
+    pub fn with_loops(&self) -> Grammar {
+        let mut rules = self.rules.clone();
+        rules.insert(8, Rule::Alternatives(vec![vec![42], vec![42, 8]]));
+        rules.insert(11, Rule::Alternatives(vec![vec![42, 31], vec![42, 11, 31]]));
+        Grammar { rules }
+    }
+
+    /// Every position `rule` can stop at when matched from `start` of `message`, without duplicates.
+    /// Backtracks through all the alternatives, `seen` remembers what was already worked out for this message.
+    /// A rule that comes back to itself before matching a character gets nothing from doing so, instead of
+    /// never ending; every rule of the puzzle matches a character first. Undefined rules match nothing.
+    fn ends(&self, rule: usize, message: &str, start: usize, seen: &mut HashMap<(usize, usize), Vec<usize>>) -> Vec<usize> {
+        if let Some(ends) = seen.get(&(rule, start)) {
+            return ends.clone();
+        }
+        seen.insert((rule, start), Vec::new());
+        let mut ends = match self.rules.get(&rule) {
+            Some(Rule::Literal(c)) if message[start..].starts_with(*c) => vec![start + c.len_utf8()],
+            Some(Rule::Alternatives(alternatives)) => alternatives.iter().flat_map(|sequence| {
+                sequence.iter().fold(vec![start], |starts, &next| {
+                    let mut ends = starts.into_iter().flat_map(|at| self.ends(next, message, at, seen)).collect::<Vec<_>>();
+                    ends.sort_unstable();
+                    ends.dedup();
+                    ends
+                })
+            }).collect(),
+            _ => Vec::new(),
+        };
+        ends.sort_unstable();
+        ends.dedup();
+        seen.insert((rule, start), ends.clone());
+        ends
+    }
+}
+
+/// Rules, a blank line, then the received messages
+#[derive(Debug, Clone)]
+pub struct Messages {
+    pub grammar: Grammar,
+    pub messages: Vec<String>,
+}
+
+impl FromStr for Messages {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (rules, messages) = s.split_once("\n\n")
+            .ok_or_else(|| ParseError::new(s, &s[s.len()..], "a blank line between the rules and the messages"))?;
+        Ok(Messages {
+            grammar: Grammar::from_str(rules)?,
+            messages: messages.lines().map(str::trim).filter(|line| !line.is_empty()).map(str::to_string).collect(),
+        })
+    }
+}
+
+impl Messages {
+    /// How many messages `grammar` matches completely
+    pub fn count_matching(&self, grammar: &Grammar) -> usize {
+        self.messages.iter().filter(|message| grammar.matches(message)).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grammar, Messages, Rule};
+    use std::str::FromStr;
+
+    #[test]
+    fn parsing_test() {
+        let grammar = Grammar::from_str("0: 1 2 | 2 1\n1: \"a\"\n2: \"b\"").unwrap();
+        assert_eq!(grammar.rules[&0], Rule::Alternatives(vec![vec![1, 2], vec![2, 1]]));
+        assert_eq!(grammar.rules[&1], Rule::Literal('a'));
+        assert!(grammar.matches("ab") && grammar.matches("ba") && !grammar.matches("aa") && !grammar.matches("abb"));
+        assert_eq!(Grammar::from_str("0: 1\n1: \"ab\"").unwrap_err().to_string(),
+                   "line 2, column 4: expected a single quoted character like `\"a\"`, found \"\\\"ab\\\"\"");
+        assert_eq!(Grammar::from_str("0: 1 | \n1: \"a\"").unwrap_err().expected, "rule numbers or a quoted character");
+        assert_eq!(Grammar::from_str("0: 1 x").unwrap_err().to_string(), "line 1, column 6: expected a number, found \"x\"");
+        assert_eq!(Grammar::from_str("0: 1\n1: 2").unwrap_err().to_string(),
+                   "line 2, column 1: expected rules that are all defined, there's no rule 2, found \"1: 2\"");
+        assert_eq!(Grammar::from_str("1: \"a\"\n1: \"b\"").unwrap_err().expected, "a rule number not used before");
+        assert_eq!(Grammar::from_str("1: \"a\"").unwrap_err().expected, "a rule 0");
+        assert!(Messages::from_str("0: \"a\"").is_err());
+    }
+
+    #[test]
+    fn test_from_example() {
+        let input = "0: 4 1 5\n1: 2 3 | 3 2\n2: 4 4 | 5 5\n3: 4 5 | 5 4\n4: \"a\"\n5: \"b\"\n\nababbb\nbababa\nabbbab\naaabbb\naaaabbb";
+        let messages = Messages::from_str(input).unwrap();
+        assert_eq!(messages.count_matching(&messages.grammar), 2);
+    }
+
+    #[test]
+    fn loops() {
+        let messages = Messages::from_str(&std::fs::read_to_string("./day-nineteen/messages.dat").unwrap()).unwrap();
+        assert_eq!(messages.count_matching(&messages.grammar), 3);
+        assert_eq!(messages.count_matching(&messages.grammar.with_loops()), 12);
+        // Left recursion ends too
+        let grammar = Grammar::from_str("0: 0 1 | 1\n1: \"a\"").unwrap();
+        assert!(grammar.matches("a") && !grammar.matches("b"));
+    }
+}
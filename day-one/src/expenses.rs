@@ -0,0 +1,55 @@
+pub struct Data {
+    entries: Vec<u32>
+}
+
+impl From<Vec<u32>> for Data {
+    fn from(entries: Vec<u32>) -> Data {
+        Data { entries }
+    }
+}
+
+impl Data {
+    pub fn part_one(&self) -> Vec<u32> {
+        let mut ans: Vec<u32> = Vec::new();
+        for (val, first) in self.entries.iter().enumerate() {
+            for second in &self.entries[val..] {
+                if first + second == 2020 {
+                    ans.push(first * second)
+                }
+            }
+        };
+        ans
+    }
+    pub fn part_two(&self) -> Option<u32> {
+        for (val, first) in self.entries.iter().enumerate() {
+            for (deep, second) in self.entries.iter().enumerate().skip(val) {
+                for third in &self.entries[deep..] {
+                    if first + second + third == 2020 {
+                        return Some(first * second * third);
+                    }
+                }
+            }
+        };
+        None
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Data;
+
+    const EXAMPLE: [u32; 6] = [1721, 979, 366, 299, 675, 1456];
+
+    #[test]
+    fn one() {
+        let data = Data::from(EXAMPLE.to_vec());
+        assert!(data.part_one().contains(&514579))
+    }
+
+    #[test]
+    fn two() {
+        let data = Data::from(EXAMPLE.to_vec());
+        assert_eq!(data.part_two(), Some(241861950))
+    }
+}
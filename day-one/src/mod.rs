@@ -0,0 +1,39 @@
+pub mod expenses;
+
+use crate::error::{ParseError, NoAnswer, parse_lines, number};
+use crate::solution::Solution;
+use crate::generate::Rng;
+use rand::Rng as _;
+use rand::seq::SliceRandom;
+use expenses::Data;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = "./day-one/expenses.dat";
+    const REVISION: u32 = 2;
+    type Input = Data;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Data, ParseError> {
+        parse_lines(input, |line| number(line, line.trim())).map(Data::from)
+    }
+    fn part_one(data: &Data) -> Result<u32, NoAnswer> {
+        data.part_one().first().copied().ok_or_else(|| NoAnswer::new("two entries that sum to 2020"))
+    }
+    fn part_two(data: &Data) -> Result<u32, NoAnswer> {
+        data.part_two().ok_or_else(|| NoAnswer::new("three entries that sum to 2020"))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // A pair and a triple add up to 2020, the rest is random
+        let pair = rng.gen_range(1..2020);
+        let first = rng.gen_range(1..2018);
+        let second = rng.gen_range(1..2019 - first);
+        let mut expenses = vec![pair, 2020 - pair, first, second, 2020 - first - second];
+        expenses.extend((5..size).map(|_| rng.gen_range(1..2020)));
+        expenses.shuffle(rng);
+        expenses.iter().map(u32::to_string).collect::<Vec<_>>().join("\n")
+    }
+}
@@ -0,0 +1,64 @@
+pub mod bags;
+
+use crate::error::{ParseError, NoAnswer};
+use crate::solution::Solution;
+use crate::generate::{self, Rng};
+use rand::Rng as _;
+use bags::{Bag, BagRule, validate_bag, inside_bag};
+
+pub struct Day7;
+
+impl Day7 {
+    fn shiny_gold() -> Bag {
+        Bag { color: "shiny gold".to_string() }
+    }
+}
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = "./day-seven/bags.dat";
+    type Input = Vec<BagRule>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<BagRule>, ParseError> {
+        BagRule::from_string(input)
+    }
+    fn part_one(rules: &Vec<BagRule>) -> Result<usize, NoAnswer> {
+        Ok(validate_bag(rules, &Day7::shiny_gold()))
+    }
+    fn part_two(rules: &Vec<BagRule>) -> Result<usize, NoAnswer> {
+        Ok(inside_bag(rules, &Day7::shiny_gold()))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const ADJECTIVES: [&str; 24] = ["bright", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "muted",
+            "mirrored", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "clear", "dashed", "faint",
+            "mottled", "shimmery", "vivid", "dusky"];
+        const COLORS: [&str; 24] = ["aqua", "beige", "black", "blue", "bronze", "brown", "chartreuse", "coral", "crimson",
+            "cyan", "fuchsia", "gold", "gray", "green", "indigo", "lavender", "lime", "magenta", "maroon", "olive",
+            "orange", "plum", "red", "tan"];
+        let shiny_gold = Day7::shiny_gold().color;
+        let colors = ADJECTIVES.iter()
+            .flat_map(|adjective| COLORS.iter().map(move |color| format!("{} {}", adjective, color)))
+            .filter(|color| *color != shiny_gold)
+            .collect::<Vec<_>>();
+        let count = size.clamp(1, colors.len());
+        let mut bags = generate::distinct(rng, &colors, count - 1);
+        // Bags only hold the ones listed after them, so rules never loop and few enough fit in shiny gold
+        bags.insert(count.saturating_sub(12).max(count / 2), shiny_gold);
+        bags.iter().enumerate().map(|(index, bag)| {
+            let inside = (index + 1..count).collect::<Vec<_>>();
+            let kinds = rng.gen_range(0..=3.min(inside.len()));
+            let contents = generate::distinct(rng, &inside, kinds).into_iter()
+                .map(|inner| match rng.gen_range(1..=5) {
+                    1 => format!("1 {} bag", bags[inner]),
+                    many => format!("{} {} bags", many, bags[inner]),
+                })
+                .collect::<Vec<_>>();
+            match contents.is_empty() {
+                true => format!("{} bags contain no other bags.", bag),
+                false => format!("{} bags contain {}.", bag, contents.join(", ")),
+            }
+        }).collect::<Vec<_>>().join("\n")
+    }
+}
@@ -4,9 +4,8 @@ use itertools::MinMaxResult::{OneElement, MinMax, NoElements};
 use std::fmt::{Display, Formatter};
 use std::ops::{RangeInclusive, Sub};
 use std::fmt;
-use num::traits::AsPrimitive;
-use debug_print::{debug_print};
-use num::Signed;
+use crate::error::ParseError;
+use super::{initial_layer, layer};
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 struct Position {
@@ -16,7 +15,7 @@ struct Position {
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct PocketDimension {
+pub struct PocketDimension {
     active_cubes: Vec<Position>
 }
 
@@ -24,6 +23,10 @@ impl PocketDimension {
     pub fn active_cubes(&self) -> usize {
         self.active_cubes.len()
     }
+    /// `(x, y, z, w)` of every active cube, `w` is always 0
+    pub fn cubes(&self) -> impl Iterator<Item=(i32, i32, i32, i32)> + '_ {
+        self.active_cubes.iter().map(|cube| (cube.x, cube.y, cube.z, 0))
+    }
     fn active_neighbours(&self, pos: &Position) -> usize {
         self.active_cubes.iter()
             .filter(|&active| active != pos)
@@ -51,21 +54,22 @@ impl PocketDimension {
         self.active_cubes = new_active;
         self
     }
+    /// Smallest box holding every active cube, empty when none is left
     fn get_active_ranges(&self) -> PositionRange {
         let x_range = match self.active_cubes.iter().map(|cube| cube.x).minmax() {
             OneElement(x) => RangeInclusive::new(x, x),
             MinMax(min, max) => RangeInclusive::new(min, max),
-            NoElements => panic!()
+            NoElements => RangeInclusive::new(0, -1)
         };
         let y_range = match self.active_cubes.iter().map(|cube| cube.y).minmax() {
             OneElement(y) => RangeInclusive::new(y, y),
             MinMax(min, max) => RangeInclusive::new(min, max),
-            NoElements => panic!()
+            NoElements => RangeInclusive::new(0, -1)
         };
         let z_range = match self.active_cubes.iter().map(|cube| cube.z).minmax() {
             OneElement(z) => RangeInclusive::new(z, z),
             MinMax(min, max) => RangeInclusive::new(min, max),
-            NoElements => panic!()
+            NoElements => RangeInclusive::new(0, -1)
         };
         PositionRange {
             x: x_range,
@@ -76,17 +80,12 @@ impl PocketDimension {
 }
 
 impl FromStr for PocketDimension {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let active = s.split("\n").enumerate().map(|(y, line_str)|
-            line_str.chars().enumerate().filter_map(|(x, char)|
-                match char {
-                    '#' => Some(Position { x: x as i32, y: y as i32, z: 0 }),
-                    _ => None
-                })
-                .collect_vec()
-        ).flatten().collect_vec();
+        let active = initial_layer(s)?.into_iter()
+            .map(|(x, y)| Position { x, y, z: 0 })
+            .collect_vec();
         Ok(PocketDimension { active_cubes: active })
     }
 }
@@ -101,7 +100,7 @@ impl PositionRange {
     pub fn expand_by_one(&self) -> PositionRange{
         self.expand_by(1)
     }
-    pub fn expand_by(&self, i: i32) -> PositionRange{
+    pub fn expand_by(&self, _i: i32) -> PositionRange{
         PositionRange{
             x: RangeInclusive::new(self.x.start()-1, self.x.end()+1),
             y: RangeInclusive::new(self.y.start()-1, self.y.end()+1),
@@ -115,14 +114,8 @@ impl Display for PocketDimension {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let range = self.get_active_ranges();
         let str = range.z.clone().map(|z| {
-            [format!("z={}", z),
-                range.y.clone().map(|y| {
-                    let mut line = ".".repeat(range.x.clone().count()).into_bytes();
-                    self.active_cubes.iter().filter(|pos| pos.z == z && pos.y == y)
-                        .for_each(|pos| line[(pos.x - range.x.start()) as usize] = '#'.as_());
-                    String::from_utf8(line).unwrap()
-                })
-                    .join("\n")].join("\n")
+            let active = self.active_cubes.iter().filter(|pos| pos.z == z).map(|pos| (pos.x, pos.y));
+            format!("z={}\n{}", z, layer(&range.x, &range.y, active))
         }).join("\n\n");
         write!(f, "{}", str)
     }
@@ -130,10 +123,9 @@ impl Display for PocketDimension {
 
 #[cfg(test)]
 mod tests {
-    use crate::convay_cubes_3D::PocketDimension;
+    use super::{PocketDimension, Position};
     use std::str::FromStr;
-    use debug_print::{debug_print};
-    use crate::convay_cubes_3D::Position;
+    use tracing::trace;
 
     #[test]
     pub fn example() {
@@ -144,9 +136,10 @@ mod tests {
         assert!(pocket.is_ok());
         let pocket = pocket.unwrap();
         assert_eq!(pocket.active_cubes.len(), 5);
-        // debug_print!("MAP: {:?}",pocket);
-        debug_print!("{}",pocket);
-        debug_print!("{:?}\n",pocket);
+        assert_eq!(pocket.to_string(), format!("z=0\n{}", input));
+        // trace!("MAP: {:?}",pocket);
+        trace!("{}",pocket);
+        trace!("{:?}",pocket);
         assert_eq!(pocket.active_neighbours(&Position { x: 1, y: 1, z: 0 }), 5);
         assert_eq!(pocket.active_neighbours(&Position { x: 0, y: 0, z: 0 }), 1);
         assert_eq!(pocket.active_neighbours(&Position { x: 2, y: 0, z: 0 }), 2);
@@ -154,13 +147,18 @@ mod tests {
 
 
         let mut pocket = pocket.clone();
-        debug_print!("After 1 cycle:\n\n{}\n",pocket.update());
-        debug_print!("After 2 cycles:\n\n{}\n",pocket.update());
-        debug_print!("After 3 cycles:\n\n{}\n",pocket.update());
-        debug_print!("After 4 cycles:\n\n{}\n",pocket.update());
-        debug_print!("After 5 cycles:\n\n{}\n",pocket.update());
-        debug_print!("After 6 cycles:\n\n{}\n",pocket.update());
+        (1..=6).for_each(|cycle| {
+            let updated = pocket.update();
+            trace!("After {} cycles:\n\n{}", cycle, updated);
+        });
         assert_eq!(pocket.active_cubes(),112)
     }
 
+    #[test]
+    fn no_active_cubes() {
+        let mut pocket = PocketDimension::from_str("...\n...").unwrap();
+        assert_eq!(pocket.to_string(), "");
+        assert_eq!(pocket.update().active_cubes(), 0);
+    }
+
 }
\ No newline at end of file
@@ -4,9 +4,9 @@ use itertools::MinMaxResult::{OneElement, MinMax, NoElements};
 use std::fmt::{Display, Formatter};
 use std::ops::{RangeInclusive, Sub};
 use std::fmt;
-use num::traits::AsPrimitive;
-use debug_print::{debug_print};
-use num::Signed;
+use tracing::debug;
+use crate::error::ParseError;
+use super::{initial_layer, layer};
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 struct Position4D {
@@ -17,7 +17,7 @@ struct Position4D {
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct PocketDimension4D {
+pub struct PocketDimension4D {
     active_cubes: Vec<Position4D>
 }
 
@@ -25,6 +25,10 @@ impl PocketDimension4D {
     pub fn active_cubes(&self) -> usize {
         self.active_cubes.len()
     }
+    /// `(x, y, z, w)` of every active cube
+    pub fn cubes(&self) -> impl Iterator<Item=(i32, i32, i32, i32)> + '_ {
+        self.active_cubes.iter().map(|cube| (cube.x, cube.y, cube.z, cube.w))
+    }
     fn active_neighbours(&self, pos: &Position4D) -> usize {
         self.active_cubes.iter()
             .filter(|&active| active != pos)
@@ -53,31 +57,32 @@ impl PocketDimension4D {
             })
         });
         if self.active_cubes == new_active {
-            panic!("PocketDimension in stable state {}",self)
+            debug!("PocketDimension in stable state {}", self)
         }
         self.active_cubes = new_active;
         self
     }
+    /// Smallest box holding every active cube, empty when none is left
     fn get_active_ranges(&self) -> PositionRange4D {
         let x_range = match self.active_cubes.iter().map(|cube| cube.x).minmax() {
             OneElement(x) => RangeInclusive::new(x, x),
             MinMax(min, max) => RangeInclusive::new(min, max),
-            NoElements => panic!()
+            NoElements => RangeInclusive::new(0, -1)
         };
         let y_range = match self.active_cubes.iter().map(|cube| cube.y).minmax() {
             OneElement(y) => RangeInclusive::new(y, y),
             MinMax(min, max) => RangeInclusive::new(min, max),
-            NoElements => panic!()
+            NoElements => RangeInclusive::new(0, -1)
         };
         let z_range = match self.active_cubes.iter().map(|cube| cube.z).minmax() {
             OneElement(z) => RangeInclusive::new(z, z),
             MinMax(min, max) => RangeInclusive::new(min, max),
-            NoElements => panic!()
+            NoElements => RangeInclusive::new(0, -1)
         };
         let w_range = match self.active_cubes.iter().map(|cube| cube.w).minmax() {
             OneElement(w) => RangeInclusive::new(w, w),
             MinMax(min, max) => RangeInclusive::new(min, max),
-            NoElements => panic!()
+            NoElements => RangeInclusive::new(0, -1)
         };
         PositionRange4D {
             x: x_range,
@@ -89,17 +94,12 @@ impl PocketDimension4D {
 }
 
 impl FromStr for PocketDimension4D {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let active = s.split("\n").enumerate().map(|(y, line_str)|
-            line_str.chars().enumerate().filter_map(|(x, char)|
-                match char {
-                    '#' => Some(Position4D { x: x as i32, y: y as i32, z: 0, w:0}),
-                    _ => None
-                })
-                .collect_vec()
-        ).flatten().collect_vec();
+        let active = initial_layer(s)?.into_iter()
+            .map(|(x, y)| Position4D { x, y, z: 0, w: 0 })
+            .collect_vec();
         Ok(PocketDimension4D { active_cubes: active })
     }
 }
@@ -116,7 +116,7 @@ impl PositionRange4D {
     pub fn expand_by_one(&self) -> PositionRange4D {
         self.expand_by(1)
     }
-    pub fn expand_by(&self, i: i32) -> PositionRange4D {
+    pub fn expand_by(&self, _i: i32) -> PositionRange4D {
         PositionRange4D {
             x: RangeInclusive::new(self.x.start() - 1, self.x.end() + 1),
             y: RangeInclusive::new(self.y.start() - 1, self.y.end() + 1),
@@ -129,15 +129,9 @@ impl PositionRange4D {
 impl Display for PocketDimension4D {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let range = self.get_active_ranges();
-        let str = range.z.clone().map(|z| {
-            [format!("z={}", z),
-                range.y.clone().map(|y| {
-                    let mut line = ".".repeat(range.x.clone().count()).into_bytes();
-                    self.active_cubes.iter().filter(|pos| pos.z == z && pos.y == y)
-                        .for_each(|pos| line[(pos.x - range.x.start()) as usize] = '#'.as_());
-                    String::from_utf8(line).unwrap()
-                })
-                    .join("\n")].join("\n")
+        let str = range.w.clone().cartesian_product(range.z.clone()).map(|(w, z)| {
+            let active = self.active_cubes.iter().filter(|pos| pos.z == z && pos.w == w).map(|pos| (pos.x, pos.y));
+            format!("z={}, w={}\n{}", z, w, layer(&range.x, &range.y, active))
         }).join("\n\n");
         write!(f, "{}", str)
     }
@@ -145,10 +139,9 @@ impl Display for PocketDimension4D {
 
 #[cfg(test)]
 mod tests {
-    use crate::convay_cubes_4D::PocketDimension4D;
+    use super::{PocketDimension4D, Position4D};
     use std::str::FromStr;
-    use debug_print::{debug_print};
-    use crate::convay_cubes_4D::Position4D;
+    use tracing::trace;
 
     #[test]
     pub fn example() {
@@ -159,9 +152,9 @@ mod tests {
         assert!(pocket.is_ok());
         let pocket = pocket.unwrap();
         assert_eq!(pocket.active_cubes.len(), 5);
-        // debug_print!("MAP: {:?}",pocket);
-        debug_print!("{}",pocket);
-        debug_print!("{:?}\n",pocket);
+        // trace!("MAP: {:?}",pocket);
+        trace!("{}",pocket);
+        trace!("{:?}",pocket);
         assert_eq!(pocket.active_neighbours(&Position4D { x: 1, y: 1, z: 0, w:0 }), 5);
         assert_eq!(pocket.active_neighbours(&Position4D { x: 0, y: 0, z: 0, w:0 }), 1);
         assert_eq!(pocket.active_neighbours(&Position4D { x: 2, y: 0, z: 0, w:0 }), 2);
@@ -169,12 +162,17 @@ mod tests {
 
 
         let mut pocket = pocket.clone();
-        debug_print!("After 1 cycle:\n\n{}\n",pocket.update());
-        debug_print!("After 2 cycles:\n\n{}\n",pocket.update());
-        debug_print!("After 3 cycles:\n\n{}\n",pocket.update());
-        debug_print!("After 4 cycles:\n\n{}\n",pocket.update());
-        debug_print!("After 5 cycles:\n\n{}\n",pocket.update());
-        debug_print!("After 6 cycles:\n\n{}\n",pocket.update());
+        (1..=6).for_each(|cycle| {
+            let updated = pocket.update();
+            trace!("After {} cycles:\n\n{}", cycle, updated);
+        });
         assert_eq!(pocket.active_cubes(), 848)
     }
+
+    #[test]
+    fn no_active_cubes() {
+        let mut pocket = PocketDimension4D::from_str("...\n...").unwrap();
+        assert_eq!(pocket.to_string(), "");
+        assert_eq!(pocket.update().update().active_cubes(), 0);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,128 @@
+pub mod convay_cubes_3d;
+pub mod convay_cubes_4d;
+
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use itertools::Itertools;
+use crate::animate::Frame;
+use crate::error::{ParseError, NoAnswer};
+use crate::grid::{Grid, Tile};
+use crate::solution::Solution;
+use crate::generate::{self, Rng};
+use convay_cubes_3d::PocketDimension;
+use convay_cubes_4d::PocketDimension4D;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Cube {
+    Inactive,
+    Active,
+}
+
+impl Tile for Cube {
+    const EXPECTED: &'static str = "`#` or `.`";
+    fn from_char(c: char) -> Option<Cube> {
+        match c {
+            '#' => Some(Cube::Active),
+            '.' => Some(Cube::Inactive),
+            _ => None
+        }
+    }
+    fn to_char(&self) -> char {
+        match self {
+            Cube::Active => '#',
+            Cube::Inactive => '.',
+        }
+    }
+}
+
+/// `(x, y)` of every active cube in the initial slice
+pub fn initial_layer(s: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+    let layer = Grid::<Cube>::from_str(s)?;
+    Ok(layer.iter()
+        .filter(|(_, &cube)| cube == Cube::Active)
+        .map(|((x, y), _)| (x as i32, y as i32))
+        .collect())
+}
+
+/// Slice through the pocket dimension spanning `x` by `y`, with `active` cubes given as `(x, y)`
+pub fn layer<I>(x: &RangeInclusive<i32>, y: &RangeInclusive<i32>, active: I) -> Grid<Cube>
+    where I: IntoIterator<Item=(i32, i32)> {
+    let mut layer = Grid::new(x.clone().count(), y.clone().count(), Cube::Inactive);
+    for (cube_x, cube_y) in active {
+        layer[((cube_x - x.start()) as usize, (cube_y - y.start()) as usize)] = Cube::Active;
+    }
+    layer
+}
+
+pub struct Day17;
+
+impl Day17 {
+    const CYCLES: usize = 6;
+
+    /// The pocket dimension before and after every cycle. Each frame lays out the `z` slices left to right and the `w`
+    /// slices top to bottom, spanning every cube of every cycle so all frames line up.
+    pub fn frames((pocket, pocket_4d): &(PocketDimension, PocketDimension4D), part: u8) -> Vec<Frame> {
+        let cycles = match part {
+            1 => (0..=Day17::CYCLES).scan(pocket.clone(), |pocket, cycle| {
+                if cycle > 0 {
+                    pocket.update();
+                }
+                Some(pocket.cubes().collect::<Vec<_>>())
+            }).collect::<Vec<_>>(),
+            _ => (0..=Day17::CYCLES).scan(pocket_4d.clone(), |pocket, cycle| {
+                if cycle > 0 {
+                    pocket.update();
+                }
+                Some(pocket.cubes().collect::<Vec<_>>())
+            }).collect(),
+        };
+        let span = |axis: fn(&(i32, i32, i32, i32)) -> i32| match cycles.iter().flatten().map(axis).minmax().into_option() {
+            Some((min, max)) => min..=max,
+            None => 0..=0,
+        };
+        let (x, y, z, w) = (span(|cube| cube.0), span(|cube| cube.1), span(|cube| cube.2), span(|cube| cube.3));
+        cycles.iter().enumerate().map(|(cycle, cubes)| {
+            let text = w.clone().map(|slice_w| {
+                let slices = z.clone().map(|slice_z| {
+                    let active = cubes.iter().filter(|cube| cube.2 == slice_z && cube.3 == slice_w).map(|cube| (cube.0, cube.1));
+                    layer(&x, &y, active).to_string()
+                }).collect::<Vec<_>>();
+                (0..y.clone().count()).map(|row| slices.iter().map(|slice| slice.lines().nth(row).unwrap_or_default()).join(" ")).join("\n")
+            }).join("\n\n");
+            let title = match part {
+                1 => format!("Day 17 part 1, cycle {}: {} active cubes (z {:?} left to right)", cycle, cubes.len(), z),
+                _ => format!("Day 17 part 2, cycle {}: {} active cubes (z {:?} left to right, w {:?} top to bottom)", cycle, cubes.len(), z, w),
+            };
+            Frame { title, text }
+        }).collect()
+    }
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const INPUT: &'static str = "./day-seventeen/cubes.dat";
+    type Input = (PocketDimension, PocketDimension4D);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((PocketDimension::from_str(input)?, PocketDimension4D::from_str(input)?))
+    }
+    fn part_one((pocket, _): &Self::Input) -> Result<usize, NoAnswer> {
+        let mut pocket = pocket.clone();
+        (0..Day17::CYCLES).for_each(|_| { pocket.update(); });
+        Ok(pocket.active_cubes())
+    }
+    fn part_two((_, pocket): &Self::Input) -> Result<usize, NoAnswer> {
+        let mut pocket = pocket.clone();
+        (0..Day17::CYCLES).for_each(|_| { pocket.update(); });
+        Ok(pocket.active_cubes())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // The example glider in the corner keeps the pocket dimension from dying out
+        let side = size.clamp(3, 32);
+        let mut map = generate::character_map(rng, side, side, ('#', 0.4), '.').lines().map(String::from).collect::<Vec<_>>();
+        [".#.", "..#", "###"].iter().zip(map.iter_mut()).for_each(|(glider, row)| row.replace_range(0..3, glider));
+        map.join("\n")
+    }
+}
@@ -0,0 +1,71 @@
+use itertools::Itertools;
+use tracing::trace;
+use crate::error::ParseError;
+
+
+pub fn count_group(input: &str) -> usize {
+    let person = input.split('\n').collect_vec();
+    person.into_iter().join("").as_str().chars().unique().count()
+}
+
+pub fn sum_groups(input: &str) -> usize {
+    let passport = input.split("\n\n");
+    passport.map(count_group).sum()
+}
+
+pub fn count_group_two(input: &str) -> usize {
+    let person = input.split('\n').collect_vec();
+    let unique_ans = person.iter().join("").as_str().chars().unique().collect_vec();
+    trace!("All answers: {:?} : Individual answers {:?}", unique_ans, person);
+    unique_ans.iter()
+        .filter(|&&c|
+            person.iter().all(|&person_ans|
+                person_ans.chars().any(|p| p == c)))
+        .count()
+}
+
+pub fn sum_groups_two(input: &str) -> usize {
+    let passport = input.split("\n\n");
+    passport.map(count_group_two).sum()
+}
+
+/// Answers are lowercase letters, one person per line
+pub fn check_answers(input: &str) -> Result<(), ParseError> {
+    match input.char_indices().find(|&(_, c)| !c.is_ascii_lowercase() && c != '\n') {
+        Some((at, c)) => Err(ParseError::new(input, &input[at..at + c.len_utf8()], "a question letter from `a` to `z`")),
+        None => Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sum_groups, sum_groups_two, check_answers};
+
+    #[test]
+    fn su_test() {
+        let input = r#"abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b"#;
+        assert_eq!(sum_groups(input), 11);
+        assert_eq!(sum_groups_two(input), 6);
+    }
+
+    #[test]
+    fn malformed_answers() {
+        assert_eq!(check_answers("abc\n\na\nb"), Ok(()));
+        let error = check_answers("abc\n\na\nB").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (4, 1, "B"));
+    }
+}
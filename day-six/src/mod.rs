@@ -0,0 +1,37 @@
+pub mod customs;
+
+use crate::error::{ParseError, NoAnswer};
+use crate::solution::Solution;
+use crate::generate::{self, Rng};
+use rand::Rng as _;
+use customs::{sum_groups, sum_groups_two, check_answers};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = "./day-six/answers.dat";
+    type Input = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        check_answers(input)?;
+        Ok(input.to_string())
+    }
+    fn part_one(answers: &String) -> Result<usize, NoAnswer> {
+        Ok(sum_groups(answers))
+    }
+    fn part_two(answers: &String) -> Result<usize, NoAnswer> {
+        Ok(sum_groups_two(answers))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let questions = (b'a'..=b'z').collect::<Vec<u8>>();
+        (0..size).map(|_| {
+            (0..rng.gen_range(1..=5)).map(|_| {
+                let count = rng.gen_range(1..=questions.len());
+                String::from_utf8(generate::distinct(rng, &questions, count)).unwrap()
+            }).collect::<Vec<_>>().join("\n")
+        }).collect::<Vec<_>>().join("\n\n")
+    }
+}
@@ -0,0 +1,56 @@
+pub mod tickets;
+
+use std::str::FromStr;
+use crate::error::{ParseError, NoAnswer};
+use crate::solution::Solution;
+use crate::generate::{self, Rng};
+use rand::Rng as _;
+use rand::seq::SliceRandom;
+use tickets::TicketsData;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const INPUT: &'static str = "./day-sixteen/tickets.dat";
+    type Input = TicketsData;
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<TicketsData, ParseError> {
+        TicketsData::from_str(input)
+    }
+    fn part_one(tickets: &TicketsData) -> Result<u32, NoAnswer> {
+        Ok(tickets.scanning_error_rate())
+    }
+    fn part_two(tickets: &TicketsData) -> Result<u64, NoAnswer> {
+        Ok(tickets.clone().sort_rules().get_departure_multiple())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Field `i` takes values from `10i+1..=10i+10` and its rule accepts everything up to there,
+        // so every column rules out the fields before it and part two can settle them one by one
+        let fields = (size / 2).clamp(1, 20);
+        let mut names = (0..fields).map(|field| match field {
+            0..=5 => format!("departure {}", field),
+            _ => format!("field {}", field),
+        }).collect::<Vec<_>>();
+        names.shuffle(rng);
+        let rules = names.iter().enumerate()
+            .map(|(field, name)| format!("{}: 1-{} or {}-{}", name, 10 * field + 4, 10 * field + 5, 10 * field + 10))
+            .collect::<Vec<_>>();
+        let columns = generate::distinct(rng, &(0..fields).collect::<Vec<_>>(), fields);
+        let ticket = |rng: &mut Rng| columns.iter().map(|field| rng.gen_range(10 * field + 1..=10 * field + 10)).collect::<Vec<_>>();
+        let your_ticket = ticket(rng);
+        let mut nearby = (0..size.max(fields)).map(|index| {
+            let mut values = ticket(rng);
+            if index >= fields && rng.gen_bool(0.25) {
+                values[rng.gen_range(0..fields)] = rng.gen_range(10 * fields + 1..=10 * fields + 50);
+            }
+            values
+        }).collect::<Vec<_>>();
+        nearby.shuffle(rng);
+        let line = |values: &Vec<usize>| values.iter().map(usize::to_string).collect::<Vec<_>>().join(",");
+        format!("{}\n\nyour ticket:\n{}\n\nnearby tickets:\n{}", rules.join("\n"), line(&your_ticket),
+                nearby.iter().map(line).collect::<Vec<_>>().join("\n"))
+    }
+}
@@ -1,21 +1,20 @@
-use std::ops::{Range, RangeInclusive};
-use std::pin::Pin;
+use std::ops::RangeInclusive;
 use std::str::FromStr;
 use itertools::Itertools;
-use debug_print::{debug_print};
-
-struct RuleParseError;
+use tracing::trace;
+use crate::elimination::eliminate;
+use crate::error::{ParseError, parse_lines, number};
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct Rule {
+pub struct Rule {
     name: String,
     allowed_ranges: (RangeInclusive<u16>, RangeInclusive<u16>),
 }
 
 impl Rule {
-    pub fn validate_ticket(&self, ticket: &Vec<u16>) -> Result<(), Vec<u16>> {
-        let mismatched = ticket.iter().filter(|val|
-            !self.allowed_ranges.0.contains(val) && !self.allowed_ranges.1.contains(val)
+    pub fn validate_ticket(&self, ticket: &[u16]) -> Result<(), Vec<u16>> {
+        let mismatched = ticket.iter().filter(|&&val|
+            !self.allowed_ranges.0.contains(&val) && !self.allowed_ranges.1.contains(&val)
         ).cloned().collect_vec();
         match mismatched[..] {
             [] => Ok(()),
@@ -30,55 +29,55 @@ impl Rule {
     }
 }
 
-pub fn string_to_range(s: &str) -> RangeInclusive<u16> {
+pub fn string_to_range(s: &str) -> Result<RangeInclusive<u16>, ParseError> {
     match s.trim().split_once('-') {
         Some((left, right)) => {
-            RangeInclusive::new(left.parse::<u16>().unwrap(), right.parse::<u16>().unwrap())
+            Ok(RangeInclusive::new(number(s, left)?, number(s, right)?))
         }
-        None => unimplemented!()
+        None => Err(ParseError::new(s, s.trim(), "a range like `1-3`"))
     }
 }
 
-pub fn string_to_range_tuple(s: &str) -> (RangeInclusive<u16>, RangeInclusive<u16>) {
+pub fn string_to_range_tuple(s: &str) -> Result<(RangeInclusive<u16>, RangeInclusive<u16>), ParseError> {
     match s.split_once(" or ") {
         Some((left, right)) => {
-            (string_to_range(left), string_to_range(right))
+            Ok((string_to_range(left).map_err(|error| error.within(s, left))?,
+                string_to_range(right).map_err(|error| error.within(s, right))?))
         }
-        None => unimplemented!()
+        None => Err(ParseError::new(s, s.trim(), "two ranges like `1-3 or 5-7`"))
     }
 }
 
 impl FromStr for Rule {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let name = s.split(':').next().unwrap_or("");
         match s.split_once(':') {
             Some((name, ranges)) => {
                 Ok(Rule {
                     name: name.to_string(),
-                    allowed_ranges: string_to_range_tuple(ranges),
+                    allowed_ranges: string_to_range_tuple(ranges).map_err(|error| error.within(s, ranges))?,
                 })
             }
-            None => Err(())
+            None => Err(ParseError::new(s, s, "a rule like `class: 1-3 or 5-7`"))
         }
     }
 }
 
 #[derive(Debug, Clone)]
-struct Rules(Vec<Rule>);
+pub struct Rules(Vec<Rule>);
 
 impl FromStr for Rules {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Rules(s.lines().map(|str| Rule::from_str(str).unwrap()).collect_vec()))
+        Ok(Rules(parse_lines(s.trim_end(), Rule::from_str)?))
     }
 }
 
 impl Rules {
-    pub fn validate_ticket(&self, ticket: &Vec<u16>) -> Result<(), Vec<u16>> {
-        let mut unmatched = ticket.clone();
+    pub fn validate_ticket(&self, ticket: &[u16]) -> Result<(), Vec<u16>> {
+        let mut unmatched = ticket.to_vec();
         for rule in self.0.iter() {
             match rule.validate_ticket(&unmatched) {
                 Ok(()) => return Ok(()),
@@ -97,37 +96,41 @@ impl Rules {
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct TicketsData {
+pub struct TicketsData {
     rules: Rules,
     your_ticket: Vec<u16>,
     nearby_tickets: Vec<Vec<u16>>,
 }
 
+/// Parses a comma separated `line` of `source`, which has to hold a value for each of `fields` rules
+fn ticket(source: &str, line: &str, fields: usize) -> Result<Vec<u16>, ParseError> {
+    let ticket = line.split(',').map(|value| number(source, value.trim())).collect::<Result<Vec<u16>, _>>()?;
+    match ticket.len() == fields {
+        true => Ok(ticket),
+        false => Err(ParseError::new(source, line, format!("a ticket with {} fields", fields)))
+    }
+}
+
 impl FromStr for TicketsData {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(str: &str) -> Result<Self, Self::Err> {
-        let your_ticket = str.find("\nyour ticket:").unwrap_or(0);
-        let nearby_tickets = str.find("\nnearby tickets:").unwrap_or(0);
+        const YOUR_TICKET: &str = "your ticket:";
+        const NEARBY_TICKETS: &str = "nearby tickets:";
+        let end = &str[str.len()..];
+        let your_ticket = str.find(YOUR_TICKET)
+            .ok_or_else(|| ParseError::new(str, end, format!("a `{}` section", YOUR_TICKET)))?;
+        let nearby_tickets = str[your_ticket..].find(NEARBY_TICKETS).map(|at| your_ticket + at)
+            .ok_or_else(|| ParseError::new(str, end, format!("a `{}` section", NEARBY_TICKETS)))?;
+        let rules = Rules::from_str(&str[0..your_ticket])?;
+        let fields = rules.0.len();
         Ok(TicketsData {
-            rules: Rules::from_str(&str[0..your_ticket]).unwrap(),
-            your_ticket: str[your_ticket..nearby_tickets]
-                .split(':')
-                .skip(1).next()
-                .unwrap().trim()
-                .split_terminator(',')
-                .map(|s| s.parse::<u16>())
-                .filter_map(Result::ok)
-                .collect_vec(),
-            nearby_tickets: str[nearby_tickets..]
-                .split(":")
-                .skip(1).next().unwrap_or("").trim().lines().map(|line|
-                line.trim()
-                    .split_terminator(',')
-                    .map(|s| s.parse::<u16>())
-                    .filter_map(Result::ok)
-                    .collect_vec()
-            ).collect_vec(),
+            your_ticket: ticket(str, str[your_ticket + YOUR_TICKET.len()..nearby_tickets].trim(), fields)?,
+            nearby_tickets: str[nearby_tickets + NEARBY_TICKETS.len()..].lines()
+                .map(str::trim).filter(|line| !line.is_empty())
+                .map(|line| ticket(str, line, fields))
+                .collect::<Result<_, _>>()?,
+            rules,
         })
     }
 }
@@ -137,7 +140,7 @@ impl TicketsData {
         self.nearby_tickets.iter()
             .map(|ticket| self.rules.validate_ticket(ticket))
             .filter_map(Result::err)
-            .map(|ticket_errors| ticket_errors.iter().sum::<u16>() as u32)
+            .map(|ticket_errors| ticket_errors.iter().map(|&value| value as u32).sum::<u32>())
             .sum()
     }
     pub fn valid_tickets(&self) -> Vec<Vec<u16>> {
@@ -158,19 +161,9 @@ impl TicketsData {
                     .map(|idx| candidates.remove(idx));
             });
         }
-        loop {
-            // Find rule which is only rule applicable to field
-            let single_candidate = field_rule_candidates.iter().enumerate()
-                .find(|(_, fp)| fp.len() == 1);
-            let (i, poss) = match single_candidate {
-                Some((i, v)) => (i, v[0]),
-                None => break
-            };
-            sorted_rules[i] = self.rules.0[poss].clone();
-            for fp in field_rule_candidates.iter_mut()
-            {
-                fp.iter().position(|&idx| idx == poss)
-                    .map(|idx| fp.remove(idx));
+        for (field, rule) in eliminate(field_rule_candidates).into_iter().enumerate() {
+            if let Some(rule) = rule {
+                sorted_rules[field] = self.rules.0[rule].clone();
             }
         }
         self.rules = Rules(sorted_rules);
@@ -182,7 +175,7 @@ impl TicketsData {
             |(_, rule)| rule.name.contains("departure")
         )
             .map(|(index, rule)| {
-                debug_print!("{}: {:?} : {}\n",index,rule, self.your_ticket[index]);
+                trace!("{}: {:?} : {}",index,rule, self.your_ticket[index]);
                 self.your_ticket[index] as u64
             }).product()
     }
@@ -190,9 +183,7 @@ impl TicketsData {
 
 #[cfg(test)]
 mod tests {
-    use std::ops::{Generator, GeneratorState};
-    use std::pin::Pin;
-    use crate::tickets::{Rule, string_to_range, string_to_range_tuple, Rules, TicketsData};
+    use super::{Rule, string_to_range, string_to_range_tuple, Rules, TicketsData};
     use std::str::FromStr;
     use itertools::Itertools;
 
@@ -210,18 +201,17 @@ nearby tickets:
 40,4,50
 55,2,20
 38,6,12"#;
-        println!("Parsed data: {:#?}", TicketsData::from_str(input));
         assert_eq!(TicketsData::from_str(input).unwrap().scanning_error_rate(), 71);
         assert_eq!(TicketsData::from_str(input).unwrap().valid_tickets(), vec![vec![7, 3, 47]]);
     }
 
     #[test]
     fn parsing_test() {
-        assert_eq!(string_to_range("1-3"), 1..=3);
-        assert_eq!(string_to_range("5-7"), 5..=7);
-        assert_eq!(string_to_range_tuple("1-3 or 5-7"), (1..=3, 5..=7));
+        assert_eq!(string_to_range("1-3"), Ok(1..=3));
+        assert_eq!(string_to_range("5-7"), Ok(5..=7));
+        assert_eq!(string_to_range_tuple("1-3 or 5-7"), Ok((1..=3, 5..=7)));
         assert_eq!(Rule::from_str("class: 1-3 or 5-7").unwrap(), Rule { name: "class".to_string(), allowed_ranges: (1..=3, 5..=7) });
-        assert_eq!(Rule::from_str("class: 1-3 or 5-7").unwrap().validate_ticket(&vec![7, 3, 47]), Err(vec![47]));
+        assert_eq!(Rule::from_str("class: 1-3 or 5-7").unwrap().validate_ticket(&[7, 3, 47]), Err(vec![47]));
         assert_eq!(Rule::from_str("row: 6-11 or 33-44").unwrap(), Rule { name: "row".to_string(), allowed_ranges: (6..=11, 33..=44) });
         assert_eq!(Rule::from_str("seat: 13-40 or 45-50").unwrap(), Rule { name: "seat".to_string(), allowed_ranges: (13..=40, 45..=50) });
     }
@@ -233,10 +223,10 @@ row: 6-11 or 33-44
 seat: 13-40 or 45-50"#;
         assert_eq!(Rules::from_str(input_str).unwrap().0.len(), 3);
         let rules = Rules::from_str(input_str).unwrap();
-        assert_eq!(rules.validate_ticket(&vec![7, 3, 47]), Ok(()));
-        assert_eq!(rules.validate_ticket(&vec![40, 4, 50]), Err(vec![4]));
-        assert_eq!(rules.validate_ticket(&vec![55, 2, 20]), Err(vec![55]));
-        assert_eq!(rules.validate_ticket(&vec![38, 6, 12]), Err(vec![12]));
+        assert_eq!(rules.validate_ticket(&[7, 3, 47]), Ok(()));
+        assert_eq!(rules.validate_ticket(&[40, 4, 50]), Err(vec![4]));
+        assert_eq!(rules.validate_ticket(&[55, 2, 20]), Err(vec![55]));
+        assert_eq!(rules.validate_ticket(&[38, 6, 12]), Err(vec![12]));
     }
 
     #[test]
@@ -255,8 +245,24 @@ nearby tickets:
         let mut tickets_data = TicketsData::from_str(input).unwrap();
         assert_eq!(tickets_data.valid_tickets(), tickets_data.nearby_tickets);
         tickets_data.sort_rules();
-        println!("Sorted rules: {:#?}", tickets_data.rules);
         assert_eq!(tickets_data.rules.0.iter().map(|rule| rule.name.as_str()).collect_vec(), vec!["row", "class", "seat"]);
         // assert_eq!(tickets_data.sort_rules().get_departure_multiple(),0)
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn malformed_notes() {
+        let error = Rule::from_str("class: 1-3 or 5-x").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (17, "x"));
+        assert_eq!(string_to_range("13").unwrap_err().expected, "a range like `1-3`");
+        let input = "class: 1-3 or 5-7\nrow: 6-11 or 33-44\n\nyour ticket:\n7,1\n\nnearby tickets:\n7,3,47";
+        let error = TicketsData::from_str(input).unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (8, "a ticket with 2 fields"));
+        assert_eq!(TicketsData::from_str("class: 1-3 or 5-7").unwrap_err().expected, "a `your ticket:` section");
+    }
+
+    #[test]
+    fn large_invalid_values() {
+        let input = "class: 1-3 or 5-7\nrow: 6-11 or 33-44\n\nyour ticket:\n7,1\n\nnearby tickets:\n65535,65535";
+        assert_eq!(TicketsData::from_str(input).unwrap().scanning_error_rate(), 131070);
+    }
+}
@@ -0,0 +1,63 @@
+pub mod joltage;
+
+use crate::error::{ParseError, NoAnswer, parse_lines, number};
+use crate::solution::Solution;
+use crate::generate::Rng;
+use rand::Rng as _;
+use rand::seq::SliceRandom;
+use itertools::Itertools;
+use joltage::{Adapter, AdapterBag, number_of_diferences};
+
+pub struct Day10;
+
+/// Every adapter goes in the chain, so each has to be 1 to 3 jolts above the next lower one or the outlet
+const CHAIN: &str = "a chain of every adapter, each 1 to 3 jolts above the one before";
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT: &'static str = "./day-ten/adapters.dat";
+    const REVISION: u32 = 2;
+    type Input = AdapterBag;
+    type PartOne = usize;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<AdapterBag, ParseError> {
+        let adapters = parse_lines(input, |line| number::<u32>(line, line.trim()))?.into_iter().map_into::<Adapter>().collect_vec();
+        match adapters.is_empty() {
+            true => Err(ParseError::new(input, input, "at least one adapter")),
+            false => Ok(AdapterBag::from(&*adapters))
+        }
+    }
+    fn part_one(bag: &AdapterBag) -> Result<usize, NoAnswer> {
+        let chains = bag.chain_from_wall().ok_or_else(|| NoAnswer::new(CHAIN))?;
+        let (ones, threes) = number_of_diferences(&chains[0]);
+        Ok(ones * threes)
+    }
+    fn part_two(bag: &AdapterBag) -> Result<u128, NoAnswer> {
+        match bag.all_chains_from_wall() {
+            0 => Err(NoAnswer::new(CHAIN)),
+            arrangements => Ok(arrangements),
+        }
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Runs of adapters one jolt apart, separated by three jolt gaps. A run of four multiplies the arrangements by seven.
+        const ARRANGEMENTS: [u128; 5] = [1, 1, 2, 4, 7];
+        let (mut adapters, mut rating, mut arrangements) = (Vec::new(), 0, 1u128);
+        while adapters.len() < size.max(1) {
+            let run = rng.gen_range(0..ARRANGEMENTS.len());
+            match arrangements.checked_mul(ARRANGEMENTS[run]) {
+                Some(total) if adapters.len() + run < size => arrangements = total,
+                _ if adapters.is_empty() => {},
+                _ => break,
+            }
+            rating += 3;
+            adapters.push(rating);
+            for _ in 0..run {
+                rating += 1;
+                adapters.push(rating);
+            }
+        }
+        adapters.shuffle(rng);
+        adapters.iter().map(u32::to_string).collect::<Vec<_>>().join("\n")
+    }
+}
@@ -1,25 +1,31 @@
-#![feature(test)]
-
-use itertools::{Itertools, sorted};
-use num::integer::{lcm, gcd_lcm, gcd};
-use reduce::Reduce;
+use num::integer::lcm;
 use u128 as BusType;
 use std::ops::RangeFrom;
+use crate::error::{ParseError, number};
 
-fn is_multiple(left: BusType, right: BusType) -> bool {
-    left % right == 0
+pub fn is_multiple(left: BusType, right: BusType) -> bool {
+    left.is_multiple_of(right)
 }
 
-pub fn from_str(s: &str) -> Vec<(usize, BusType)> {
+pub fn from_str(s: &str) -> Result<Vec<(usize, BusType)>, ParseError> {
     s.split(',').enumerate().filter_map(|(index, string)|
         match string {
             "x" => None,
-            _ => Some((index, string.parse().unwrap()))
+            _ => Some(match number::<BusType>(s, string) {
+                Ok(0) => Err(ParseError::new(s, string, "a bus ID above 0")),
+                bus => bus.map(|bus| (index, bus))
+            })
         }
-    ).collect_vec()
+    ).collect()
+}
+
+pub fn first_bus(departure: BusType, buses: &[(usize, BusType)]) -> Option<(BusType, BusType)> {
+    buses.iter()
+        .map(|&(_, bus)| (departure + (bus - departure % bus) % bus, bus))
+        .min()
 }
 
-pub fn earliest_v3(mut buses: Vec<(usize, BusType)>, stop_at: fn(BusType) -> bool) -> (BusType, usize) {
+pub fn earliest_v3(mut buses: Vec<(usize, BusType)>, _stop_at: fn(BusType) -> bool) -> (BusType, usize) {
     buses.sort_by_key(|(_, bus)| *bus);
     buses.into_iter().fold((0, 1), |(start_time, increment), (index, bus)| {
         RangeFrom { start: start_time }
@@ -33,7 +39,7 @@ pub fn earliest_v3(mut buses: Vec<(usize, BusType)>, stop_at: fn(BusType) -> boo
     })
 }
 
-pub fn earliest_v3_rev(mut buses: Vec<(usize, BusType)>, stop_at: fn(BusType) -> bool) -> (BusType, usize) {
+pub fn earliest_v3_rev(mut buses: Vec<(usize, BusType)>, _stop_at: fn(BusType) -> bool) -> (BusType, usize) {
     buses.sort_by_key(|(_, bus)| *bus);
     buses.into_iter().rev().fold((0, 1), |(start_time, increment), (index, bus)| {
         RangeFrom { start: start_time }
@@ -47,13 +53,13 @@ pub fn earliest_v3_rev(mut buses: Vec<(usize, BusType)>, stop_at: fn(BusType) ->
     })
 }
 
-pub fn earliest_v2(mut buses: Vec<(usize, BusType)>, stop_at: fn(BusType) -> bool) -> (BusType, usize) {
+pub fn earliest_v2(mut buses: Vec<(usize, BusType)>, _stop_at: fn(BusType) -> bool) -> (BusType, usize) {
     buses.sort_by_key(|(_, bus)| *bus);
     let mut iter_count = 0;
-    let (time, incr) = buses.into_iter().rev().fold((0, 1), |(start_time, increment), (index, bus)| {
+    let (time, _incr) = buses.into_iter().rev().fold((0, 1), |(start_time, increment), (index, bus)| {
         for i in 0.. {
             iter_count += 1;
-            if (start_time + (increment as BusType * i) + index as BusType) % bus == 0 {
+            if (start_time + (increment as BusType * i) + index as BusType).is_multiple_of(bus) {
                 return (start_time + (increment as BusType * i), lcm(increment, bus as usize));
             }
         }
@@ -64,30 +70,46 @@ pub fn earliest_v2(mut buses: Vec<(usize, BusType)>, stop_at: fn(BusType) -> boo
 
 pub fn earliest(buses: Vec<(usize, BusType)>, stop_at: fn(BusType) -> bool) -> (BusType, usize) {
     let mut iter_count = 0;
-    let mut time = 0;
-    let highest_bus = buses.iter().map(|(index, bus)| bus).max().unwrap();
-    let lowest_bus = buses.iter().map(|(index, bus)| bus).max().unwrap();
+    let highest_bus = buses.iter().map(|(_, bus)| bus).max().unwrap();
     let (start_time, inc) = buses.iter().find(|(_, bus)| bus == highest_bus).unwrap();
-    time = inc - *start_time as BusType;
+    // First time the highest bus leaves at its slot, even when the slot is past the bus ID
+    let mut time = (inc - *start_time as BusType % inc) % inc;
     loop {
         iter_count += 1;
-        time += inc;
-        if buses.iter().map(|(index, bus)| ((time + *index as BusType) % bus)).all(|x| x == 0) {
-            return (time, iter_count);
-        } else if stop_at(time) {
+        if buses.iter().all(|(index, bus)| (time + *index as BusType).is_multiple_of(*bus)) || stop_at(time) {
             return (time, iter_count);
         }
+        time += inc;
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::bus::{is_multiple, from_str, earliest, earliest_v2, earliest_v3, earliest_v3_rev};
+    use super::{is_multiple, from_str, earliest, first_bus, earliest_v2, earliest_v3, earliest_v3_rev, BusType};
     use num::integer::lcm;
+    use proptest::prelude::*;
+    use proptest::sample::subsequence;
+
+    /// Up to four distinct prime buses at distinct slots of a short schedule, small enough for the brute force `earliest`
+    fn schedule() -> impl Strategy<Value=Vec<(usize, BusType)>> {
+        const PRIMES: [BusType; 12] = [5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43];
+        subsequence(PRIMES.to_vec(), 1..=4).prop_shuffle().prop_flat_map(|buses| {
+            let count = buses.len();
+            subsequence((0..12).collect::<Vec<usize>>(), count).prop_shuffle()
+                .prop_map(move |slots| slots.into_iter().zip(buses.clone()).collect())
+        })
+    }
 
-    extern crate test;
-
-    use test::{Bencher, black_box};
+    proptest! {
+        #[test]
+        fn implementations_agree(buses in schedule()) {
+            let (time, _) = earliest_v3(buses.clone(), |_| false);
+            prop_assert!(buses.iter().all(|&(slot, bus)| (time + slot as BusType).is_multiple_of(bus)));
+            prop_assert_eq!(earliest(buses.clone(), |_| false).0, time);
+            prop_assert_eq!(earliest_v2(buses.clone(), |_| false).0, time);
+            prop_assert_eq!(earliest_v3_rev(buses, |_| false).0, time);
+        }
+    }
 
     #[test]
     fn test_ex() {
@@ -96,7 +118,7 @@ mod tests {
 
     #[test]
     fn earliest_departure() {
-        let buses = vec![7, 13, 59, 31, 19];
+        let buses = [7, 13, 59, 31, 19];
         let departure = 939;
         let res = (departure..u64::MAX).find_map(|dep|
             buses.iter().find_map(|&bus|
@@ -107,6 +129,7 @@ mod tests {
         ).unwrap();
         assert_eq!(res, (944, 59));
         assert_eq!((res.0 - departure) * res.1, 295);
+        assert_eq!(first_bus(939, &from_str("7,13,x,x,59,x,31,19").unwrap()), Some((944, 59)));
     }
 
     #[test]
@@ -127,83 +150,36 @@ mod tests {
     #[test]
     fn part_two() {
         {
-            let buses = from_str("17,x,13,19");
+            let buses = from_str("17,x,13,19").unwrap();
             assert_eq!(earliest(buses.clone(), |time| time > 3900).0, 3417);
             assert_eq!(earliest(buses.clone(), |_| false).0, earliest_v2(buses.clone(), |_| false).0);
             assert_eq!(earliest_v3(buses.clone(), |_| false).0, earliest_v2(buses.clone(), |_| false).0);
+            assert_eq!(earliest_v3_rev(buses.clone(), |_| false).0, earliest_v2(buses.clone(), |_| false).0);
         }
         {
-            let buses = from_str("67,7,59,61");
+            let buses = from_str("67,7,59,61").unwrap();
             assert_eq!(earliest(buses.clone(), |time| time > 754018 + 1).0, 754018);
             assert_eq!(earliest(buses.clone(), |_| false).0, earliest_v2(buses.clone(), |_| false).0);
             assert_eq!(earliest_v3(buses.clone(), |_| false).0, earliest_v2(buses.clone(), |_| false).0);
         }
         {
-            let buses = from_str("67,x,7,59,61");
+            let buses = from_str("67,x,7,59,61").unwrap();
             assert_eq!(earliest(buses.clone(), |time| time > 779210 + 1).0, 779210);
             assert_eq!(earliest(buses.clone(), |_| false).0, earliest_v2(buses.clone(), |_| false).0);
         }
         {
-            let buses = from_str("67,7,x,59,61");
+            let buses = from_str("67,7,x,59,61").unwrap();
             assert_eq!(earliest(buses.clone(), |time| time > 1261476 + 1).0, 1261476);
             assert_eq!(earliest(buses.clone(), |_| false).0, earliest_v2(buses.clone(), |_| false).0);
         }
         {
-            let buses = from_str("1789,37,47,1889");
+            let buses = from_str("1789,37,47,1889").unwrap();
             assert_eq!(earliest(buses.clone(), |time| time > 1202161486 + 1).0, 1202161486);
             assert_eq!(earliest(buses.clone(), |time| time > 1202161486 + 1).0, earliest_v2(buses.clone(), |time| time > 1202161486 + 1).0);
         }
         let input = r#"19,x,x,x,x,x,x,x,x,x,x,x,x,37,x,x,x,x,x,599,x,29,x,x,x,x,x,x,x,x,x,x,x,x,x,x,17,x,x,x,x,x,23,x,x,x,x,x,x,x,761,x,x,x,x,x,x,x,x,x,41,x,x,13"#;
-        let buses = from_str(input);
-        let inc = buses.iter().map(|(index, bus)| bus).fold(1, |folded, reminder| lcm(folded, *reminder));
+        let buses = from_str(input).unwrap();
+        let _inc = buses.iter().map(|(_, bus)| bus).fold(1, |folded, reminder| lcm(folded, *reminder));
         // assert_eq!(&inc, buses.iter().map(|(index, bus)| bus).max().unwrap());
     }
-
-    #[bench]
-    fn bench_earliest(b: &mut Bencher) {
-        let buses = from_str("1789,37,47,1889");
-        b.iter(|| {
-            let n = test::black_box(earliest(buses.clone(), |_| false).0);
-        });
-    }
-
-    #[bench]
-    fn bench_earliest_v2(b: &mut Bencher) {
-        let buses = from_str("1789,37,47,1889");
-        b.iter(|| {
-            let n = test::black_box(earliest_v2(buses.clone(), |_| false).0);
-        });
-    }
-
-    #[bench]
-    fn bench_earliest_v3(b: &mut Bencher) {
-        let buses = from_str("1789,37,47,1889");
-        b.iter(|| {
-            let n = test::black_box(earliest_v3(buses.clone(), |_| false).0);
-        });
-    }
-
-    #[bench]
-    fn bench_input_v2(b: &mut Bencher) {
-        let buses = from_str("19,x,x,x,x,x,x,x,x,x,x,x,x,37,x,x,x,x,x,599,x,29,x,x,x,x,x,x,x,x,x,x,x,x,x,x,17,x,x,x,x,x,23,x,x,x,x,x,x,x,761,x,x,x,x,x,x,x,x,x,41,x,x,13");
-        b.iter(|| {
-            let n = test::black_box(earliest_v2(buses.clone(), |_| false).0);
-        });
-    }
-
-    #[bench]
-    fn bench_input_v3(b: &mut Bencher) {
-        let buses = from_str("19,x,x,x,x,x,x,x,x,x,x,x,x,37,x,x,x,x,x,599,x,29,x,x,x,x,x,x,x,x,x,x,x,x,x,x,17,x,x,x,x,x,23,x,x,x,x,x,x,x,761,x,x,x,x,x,x,x,x,x,41,x,x,13");
-        b.iter(|| {
-            let n = test::black_box(earliest_v3(buses.clone(), |_| false).0);
-        });
-    }
-
-    #[bench]
-    fn bench_input_v3_rev(b: &mut Bencher) {
-        let buses = from_str("19,x,x,x,x,x,x,x,x,x,x,x,x,37,x,x,x,x,x,599,x,29,x,x,x,x,x,x,x,x,x,x,x,x,x,x,17,x,x,x,x,x,23,x,x,x,x,x,x,x,761,x,x,x,x,x,x,x,x,x,41,x,x,13");
-        b.iter(|| {
-            let n = test::black_box(earliest_v3_rev(buses.clone(), |_| false).0);
-        });
-    }
-}
\ No newline at end of file
+}
@@ -0,0 +1,47 @@
+pub mod bus;
+
+use crate::error::{ParseError, NoAnswer, number};
+use crate::solution::Solution;
+use crate::generate::{self, Rng};
+use rand::Rng as _;
+use bus::{from_str, first_bus, earliest_v3};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const INPUT: &'static str = "./day-thirteen/notes.dat";
+    const REVISION: u32 = 2;
+    /// Earliest departure timestamp and the bus schedule
+    type Input = (u128, Vec<(usize, u128)>);
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines();
+        let departure = lines.next().unwrap_or("");
+        let schedule = lines.next().ok_or_else(|| ParseError::new(input, "", "a bus schedule on the second line"))?;
+        Ok((number(input, departure.trim())?, from_str(schedule.trim()).map_err(|error| error.offset_lines(1))?))
+    }
+    fn part_one((departure, buses): &Self::Input) -> Result<u128, NoAnswer> {
+        let (time, bus) = first_bus(*departure, buses).ok_or_else(|| NoAnswer::new("at least one bus in service"))?;
+        Ok((time - departure) * bus)
+    }
+    fn part_two((_, buses): &Self::Input) -> Result<u128, NoAnswer> {
+        match buses.is_empty() {
+            true => Err(NoAnswer::new("at least one bus in service")),
+            false => Ok(earliest_v3(buses.clone(), |_| false).0),
+        }
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Bus IDs have to be coprime for part two to have an answer, primes keep it simple.
+        // Six of them under a thousand keep their product within the `usize` that `earliest_v3` steps by.
+        let slots = size.max(1);
+        let buses = generate::distinct(rng, &generate::primes(13..1000), slots.min(6));
+        let mut schedule = vec!["x".to_string(); slots];
+        schedule[0] = buses[0].to_string();
+        let others = generate::distinct(rng, &(1..slots).collect::<Vec<_>>(), buses.len() - 1);
+        others.into_iter().zip(&buses[1..]).for_each(|(slot, bus)| schedule[slot] = bus.to_string());
+        format!("{}\n{}", rng.gen_range(100_000..10_000_000), schedule.join(","))
+    }
+}
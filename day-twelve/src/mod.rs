@@ -0,0 +1,38 @@
+pub mod ship;
+
+use crate::error::{ParseError, NoAnswer, parse_lines};
+use crate::solution::Solution;
+use crate::generate::Rng;
+use rand::Rng as _;
+use rand::seq::SliceRandom;
+use ship::{Action, Ship, manhattan_distance};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const INPUT: &'static str = "./day-twelve/instructions.dat";
+    type Input = Vec<Action>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<Action>, ParseError> {
+        parse_lines(input, str::parse)
+    }
+    fn part_one(actions: &Vec<Action>) -> Result<usize, NoAnswer> {
+        let mut ship = Ship::default();
+        actions.iter().for_each(|&action| ship.move_part_1(action));
+        Ok(manhattan_distance(&ship))
+    }
+    fn part_two(actions: &Vec<Action>) -> Result<usize, NoAnswer> {
+        let mut ship = Ship::default();
+        actions.iter().for_each(|&action| ship.move_part_2(action));
+        Ok(manhattan_distance(&ship))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size).map(|_| match *b"NESWLRF".choose(rng).unwrap() {
+            turn @ (b'L' | b'R') => format!("{}{}", turn as char, [90, 180, 270].choose(rng).unwrap()),
+            action => format!("{}{}", action as char, rng.gen_range(1..=100)),
+        }).collect::<Vec<_>>().join("\n")
+    }
+}
@@ -1,9 +1,6 @@
 use std::str::FromStr;
-use std::sync::atomic::Ordering::AcqRel;
 use std::ops::{AddAssign, Mul};
-use std::fmt::Pointer;
-
-struct ShipParseError {}
+use crate::error::{ParseError, number};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 struct Position {
@@ -17,15 +14,6 @@ struct Velocity {
     east: i32,
 }
 
-impl Velocity {
-    fn from_two_points(start: Position, finish: Position) -> Velocity {
-        Velocity {
-            north: finish.north - start.north,
-            east: finish.east - start.east,
-        }
-    }
-}
-
 impl Mul<i32> for Velocity {
     type Output = Self;
 
@@ -45,7 +33,7 @@ impl AddAssign<Velocity> for Position {
 }
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Eq)]
-enum Direction {
+pub enum Direction {
     North,
     South,
     East,
@@ -60,19 +48,22 @@ pub enum Action {
 }
 
 impl FromStr for Action {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let val = s.split_at(1).1.parse::<i32>().unwrap();
-        match s.split_at(1).0 {
+        let action = s.chars().next().ok_or_else(|| ParseError::new(s, s, "an action like `F10`"))?;
+        let (action, value) = s.split_at(action.len_utf8());
+        let val = number::<i32>(s, value)?;
+        match action {
             "N" => Ok(Action::ByDirection { direction: Direction::North {}, diff: val }),
             "S" => Ok(Action::ByDirection { direction: Direction::South {}, diff: val }),
             "E" => Ok(Action::ByDirection { direction: Direction::East {}, diff: val }),
             "W" => Ok(Action::ByDirection { direction: Direction::West {}, diff: val }),
+            "L" | "R" if ![90, 180, 270].contains(&val) => Err(ParseError::new(s, value, "a turn of 90, 180 or 270 degrees")),
             "L" => Ok(Action::Turn { diff: -val }),
             "R" => Ok(Action::Turn { diff: val }),
             "F" => Ok(Action::Forward { diff: val }),
-            _ => Err(())
+            _ => Err(ParseError::new(s, action, "one of `N`, `S`, `E`, `W`, `L`, `R` or `F`"))
         }
     }
 }
@@ -107,7 +98,6 @@ impl Ship {
                 // self.turn(inst);
             }
             Action::ByDirection { direction, diff } => Self::move_self(&mut self.waypoint, direction, diff),
-            _ => panic!("Unexpected {:?}", inst)
         }
     }
     pub fn move_part_1(&mut self, inst: Action) {
@@ -115,48 +105,47 @@ impl Ship {
             Action::Forward { diff: val } => {
                 // Self::move_self(&mut self.position, self.directiob, val)
                 match self.directiob {
-                    Direction::North {} => self.position.north += val,
-                    Direction::South {} => self.position.north -= val,
-                    Direction::East {} => self.position.east += val,
-                    Direction::West {} => self.position.east -= val
+                    Direction::North => self.position.north += val,
+                    Direction::South => self.position.north -= val,
+                    Direction::East => self.position.east += val,
+                    Direction::West => self.position.east -= val
                 }
             }
-            Action::Turn { diff: val } => {
+            Action::Turn { .. } => {
                 self.turn(inst);
             }
             Action::ByDirection { direction, diff: val } =>         match direction {
-                Direction::North {} => self.position.north += val,
-                Direction::South {} => self.position.north -= val,
-                Direction::East {} => self.position.east += val,
-                Direction::West {} => self.position.east -= val
+                Direction::North => self.position.north += val,
+                Direction::South => self.position.north -= val,
+                Direction::East => self.position.east += val,
+                Direction::West => self.position.east -= val
             },
-            _ => panic!("Unexpected {:?}", inst)
         }
     }
     fn move_self(point: &mut Velocity, direction: Direction, val: i32) {
         match direction {
-            Direction::North {} => point.north += val,
-            Direction::South {} => point.north -= val,
-            Direction::East {} => point.east += val,
-            Direction::West {} => point.east -= val
+            Direction::North => point.north += val,
+            Direction::South => point.north -= val,
+            Direction::East => point.east += val,
+            Direction::West => point.east -= val
         }
     }
     fn turn(&mut self, inst: Action) {
-        const direction: [Direction; 4] = [Direction::North {}, Direction::East {}, Direction::South {}, Direction::West {}];
+        const DIRECTION: [Direction; 4] = [Direction::North {}, Direction::East {}, Direction::South {}, Direction::West {}];
         match inst {
             Action::Turn { diff: 90 | -270 } => {
-                match direction.iter().position(|&x| x == self.directiob).unwrap() + 1 {
-                    x @ 0..=3 => self.directiob = direction[x],
-                    _ => self.directiob = direction[0]
+                match DIRECTION.iter().position(|&x| x == self.directiob).unwrap() + 1 {
+                    x @ 0..=3 => self.directiob = DIRECTION[x],
+                    _ => self.directiob = DIRECTION[0]
                 }
             }
             Action::Turn { diff: -90 | 270 } => {
-                self.directiob = direction[direction.iter().position(|&x| x == self.directiob).unwrap().checked_sub(1).unwrap_or(3)]
+                self.directiob = DIRECTION[DIRECTION.iter().position(|&x| x == self.directiob).unwrap().checked_sub(1).unwrap_or(3)]
             }
             Action::Turn { diff: 180 | -180 } => {
-                let mut index = direction.iter().position(|&x| x == self.directiob).unwrap();
+                let mut index = DIRECTION.iter().position(|&x| x == self.directiob).unwrap();
                 index = index.checked_sub(2).unwrap_or(index + 2);
-                self.directiob = direction[index];
+                self.directiob = DIRECTION[index];
             }
             _ => panic!("Unexpected {:?}", inst)
         }
@@ -177,27 +166,13 @@ fn rotate_around_ship(waypoint: &Velocity, angle: f64) -> Velocity {
     pos
 }
 
-fn rotate_90(ship: &Position, waypoint: Position) -> Position {
-    // Move to 0
-    let waypoint_at_0 = Position {
-        north: waypoint.north - ship.north,
-        east: waypoint.east - ship.east,
-    };
-    let sin = std::f32::consts::FRAC_PI_2.sin() as i32;
-    let cos = std::f32::consts::FRAC_PI_2.cos() as i32;
-    Position {
-        north: cos * waypoint_at_0.north + sin * waypoint_at_0.east + ship.north,
-        east: sin * waypoint_at_0.north + cos * waypoint_at_0.east + ship.east,
-    }
-}
-
 pub fn manhattan_distance(ship: &Ship) -> usize {
     (ship.position.north.abs() + ship.position.east.abs()) as usize
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::ship::{Action, Direction, Ship, manhattan_distance, Position, rotate_around_ship, Velocity};
+    use super::{Action, Direction, Ship, manhattan_distance, rotate_around_ship, Velocity};
     use std::str::FromStr;
 
     #[test]
@@ -215,16 +190,14 @@ mod tests {
     #[test]
     fn turning_test() {
         {
-            let mut ship = Ship::default();
-            ship.directiob = Direction::South {};
+            let mut ship = Ship { directiob: Direction::South {}, ..Default::default() };
             ship.turn(Action::from_str("L180").unwrap());
             assert_eq!(ship.directiob, Direction::North {});
             ship.turn(Action::from_str("R180").unwrap());
             assert_eq!(ship.directiob, Direction::South {});
         }
         {
-            let mut ship = Ship::default();
-            ship.directiob = Direction::North {};
+            let mut ship = Ship { directiob: Direction::North {}, ..Default::default() };
             ship.turn(Action::from_str("L180").unwrap());
             assert_eq!(ship.directiob, Direction::South {});
             ship.turn(Action::from_str("R180").unwrap());
@@ -268,13 +241,13 @@ mod tests {
     #[test]
     fn move_by_string_input() {
         {
-            let INPUT = r#"F10
+            let input = r#"F10
 N3
 F7
 R90
 F11"#;
             let mut ship = Ship::default();
-            INPUT.lines().for_each(|line|
+            input.lines().for_each(|line|
                 ship.move_part_1(Action::from_str(line).unwrap())
             );
             assert_eq!(ship.position.east, 17);
@@ -309,13 +282,13 @@ F11"#;
             assert_eq!(manhattan_distance(&ship), 286)
         }
         {
-            let INPUT = r#"F10
+            let input = r#"F10
 N3
 F7
 R90
 F11"#;
             let mut ship = Ship::default();
-            INPUT.lines().for_each(|line|
+            input.lines().for_each(|line|
                 ship.move_part_2(Action::from_str(line).unwrap())
             );
             assert_eq!(ship.position.east, 214);
@@ -347,4 +320,12 @@ F11"#;
             assert_eq!(rotate_around_ship( &waypoint, 360f64), Velocity { north: 3, east: 5 });
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn malformed_action() {
+        assert_eq!(Action::from_str("").unwrap_err().expected, "an action like `F10`");
+        assert_eq!(Action::from_str("X10").unwrap_err().text, "X");
+        assert_eq!(Action::from_str("R45").unwrap_err().column, 2);
+        assert_eq!(Action::from_str("Fx").unwrap_err().expected, "a number");
+    }
+}
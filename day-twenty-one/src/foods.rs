@@ -0,0 +1,106 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+use lazy_static::lazy_static;
+use regex::Regex;
+use crate::elimination::eliminate;
+use crate::error::{ParseError, NoAnswer, parse_lines};
+
+/// Ingredients of a food and some of the allergens they contain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Food {
+    pub ingredients: Vec<String>,
+    pub allergens: Vec<String>,
+}
+
+impl FromStr for Food {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref FOOD_RE: Regex = Regex::new(r#"^(?P<ingredients>\w+(?: \w+)*)(?: \(contains (?P<allergens>\w+(?:, \w+)*)\))?$"#).unwrap();
+        }
+        let matched = FOOD_RE.captures(s.trim_end())
+            .ok_or_else(|| ParseError::new(s, s, "a food like `mxmxvkd kfcds (contains dairy, fish)`"))?;
+        Ok(Food {
+            ingredients: matched["ingredients"].split(' ').map(str::to_string).collect(),
+            allergens: matched.name("allergens").map_or_else(Vec::new, |allergens| allergens.as_str().split(", ").map(str::to_string).collect()),
+        })
+    }
+}
+
+/// Foods, one per line, with the ingredient each allergen is found in
+#[derive(Debug, Clone)]
+pub struct Foods {
+    pub foods: Vec<Food>,
+    /// Ingredients that may contain each allergen: those of every food listing it
+    pub candidates: BTreeMap<String, BTreeSet<String>>,
+    /// The one ingredient containing each allergen, for the allergens the foods narrow down to one
+    pub dangerous: BTreeMap<String, String>,
+}
+
+impl FromStr for Foods {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let foods = parse_lines(s.trim_end(), Food::from_str)?;
+        let mut candidates: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for food in &foods {
+            let ingredients = food.ingredients.iter().cloned().collect::<BTreeSet<_>>();
+            for allergen in &food.allergens {
+                candidates.entry(allergen.clone())
+                    .and_modify(|candidates| candidates.retain(|ingredient| ingredients.contains(ingredient)))
+                    .or_insert_with(|| ingredients.clone());
+            }
+        }
+        let settled = eliminate(candidates.values().map(|ingredients| ingredients.iter().cloned().collect()).collect());
+        let dangerous = candidates.keys().cloned().zip(settled)
+            .filter_map(|(allergen, ingredient)| ingredient.map(|ingredient| (allergen, ingredient)))
+            .collect();
+        Ok(Foods { foods, candidates, dangerous })
+    }
+}
+
+impl Foods {
+    /// Times an ingredient that can't contain any allergen appears in a food
+    pub fn safe_appearances(&self) -> usize {
+        let suspects = self.candidates.values().flatten().collect::<BTreeSet<_>>();
+        self.foods.iter().flat_map(|food| &food.ingredients).filter(|ingredient| !suspects.contains(ingredient)).count()
+    }
+
+    /// The dangerous ingredients in the alphabetical order of their allergens, separated by commas, as long as the
+    /// foods narrow every allergen down to one ingredient
+    pub fn canonical_dangerous_list(&self) -> Result<String, NoAnswer> {
+        self.candidates.keys()
+            .map(|allergen| self.dangerous.get(allergen).map(String::as_str)
+                .ok_or_else(|| NoAnswer::new(format!("foods that tell which ingredient contains {}", allergen))))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|ingredients| ingredients.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Food, Foods};
+    use std::str::FromStr;
+
+    #[test]
+    fn parsing_test() {
+        assert_eq!(Food::from_str("sqjhc fvjkl (contains soy)").unwrap(),
+                   Food { ingredients: vec!["sqjhc".to_string(), "fvjkl".to_string()], allergens: vec!["soy".to_string()] });
+        assert!(Food::from_str("sqjhc fvjkl").unwrap().allergens.is_empty());
+        assert_eq!(Foods::from_str("a b (contains x)\nb c (contains x,y)").unwrap_err().line, 2);
+        // Either of a and b may contain x, part one still knows c and d are safe
+        let unresolved = Foods::from_str("a b (contains x)\nb a c (contains x)\nc d").unwrap();
+        assert_eq!(unresolved.safe_appearances(), 3);
+        assert_eq!(unresolved.canonical_dangerous_list().unwrap_err().expected, "foods that tell which ingredient contains x");
+    }
+
+    #[test]
+    fn test_from_example() {
+        let input = "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)\ntrh fvjkl sbzzf mxmxvkd (contains dairy)\n\
+                     sqjhc fvjkl (contains soy)\nsqjhc mxmxvkd sbzzf (contains fish)";
+        let foods = Foods::from_str(input).unwrap();
+        assert_eq!(foods.safe_appearances(), 5);
+        assert_eq!(foods.canonical_dangerous_list().unwrap(), "mxmxvkd,sqjhc,fvjkl");
+    }
+}
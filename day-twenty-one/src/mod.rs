@@ -0,0 +1,76 @@
+pub mod foods;
+
+use std::str::FromStr;
+use crate::error::{ParseError, NoAnswer};
+use crate::solution::Solution;
+use crate::generate::{self, Rng};
+use rand::Rng as _;
+use rand::seq::SliceRandom;
+use foods::Foods;
+
+pub struct Day21;
+
+const ALLERGENS: [&str; 9] = ["dairy", "eggs", "fish", "nuts", "peanuts", "sesame", "shellfish", "soy", "wheat"];
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    /// The puzzle's example
+    const INPUT: &'static str = "./day-twenty-one/foods.dat";
+    const REVISION: u32 = 2;
+    type Input = Foods;
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Foods, ParseError> {
+        Foods::from_str(input)
+    }
+    fn part_one(foods: &Foods) -> Result<usize, NoAnswer> {
+        Ok(foods.safe_appearances())
+    }
+    fn part_two(foods: &Foods) -> Result<String, NoAnswer> {
+        foods.canonical_dangerous_list()
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Each allergen gets two foods of its own, sharing its ingredient and nothing else, so its candidates
+        // come down to that one ingredient; the other foods mix ingredients and list some of their allergens
+        let allergens = generate::distinct(rng, &ALLERGENS, (size / 3).clamp(1, ALLERGENS.len()));
+        let mut ingredients = Vec::new();
+        while ingredients.len() < allergens.len() + size + 10 {
+            let length = rng.gen_range(4..=8);
+            let ingredient = generate::word(rng, b"abcdefghijklmnopqrstvxz", length);
+            if !ingredients.contains(&ingredient) {
+                ingredients.push(ingredient);
+            }
+        }
+        let (dangerous, safe) = ingredients.split_at(allergens.len());
+        let (left, right) = safe.split_at(safe.len() / 2);
+        let pick = |rng: &mut Rng, from: &[String], count: usize| generate::distinct(rng, from, count.min(from.len()));
+        let mut foods = Vec::new();
+        for (allergen, ingredient) in allergens.iter().zip(dangerous) {
+            for half in [left, right] {
+                let count = rng.gen_range(1..=4);
+                let mut food = pick(rng, half, count);
+                food.push(ingredient.clone());
+                foods.push((food, vec![*allergen]));
+            }
+        }
+        for _ in 0..size {
+            let allergic = rng.gen_range(0..=allergens.len().min(3));
+            let contained = generate::distinct(rng, &(0..allergens.len()).collect::<Vec<_>>(), allergic);
+            let count = rng.gen_range(1..=6);
+            let mut food = pick(rng, safe, count);
+            food.extend(contained.iter().map(|&index| dangerous[index].clone()));
+            let listed = contained.iter().filter(|_| rng.gen_bool(0.5)).map(|&index| allergens[index]).collect();
+            foods.push((food, listed));
+        }
+        foods.shuffle(rng);
+        foods.into_iter().map(|(mut food, mut listed)| {
+            food.shuffle(rng);
+            listed.sort_unstable();
+            match listed.is_empty() {
+                true => food.join(" "),
+                false => format!("{} (contains {})", food.join(" "), listed.join(", ")),
+            }
+        }).collect::<Vec<_>>().join("\n")
+    }
+}
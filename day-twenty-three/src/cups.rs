@@ -0,0 +1,100 @@
+use crate::dense::DenseMap;
+use crate::error::ParseError;
+
+/// Cup labels clockwise, a digit each; the labels are 1 to however many cups there are
+pub fn labels(s: &str) -> Result<Vec<u32>, ParseError> {
+    let line = s.trim();
+    let mut labels = Vec::new();
+    for (at, c) in line.char_indices() {
+        let label = c.to_digit(10).filter(|&label| label > 0)
+            .ok_or_else(|| ParseError::new(s, &line[at..at + c.len_utf8()], "a cup label from 1 to 9"))?;
+        if labels.contains(&label) {
+            return Err(ParseError::new(s, &line[at..at + c.len_utf8()], "a label not used before"));
+        }
+        labels.push(label);
+    }
+    // Three cups are picked up and the destination is another one, fewer than five cups leaves no destination
+    if labels.len() < 5 || labels.iter().any(|&label| label as usize > labels.len()) {
+        return Err(ParseError::new(s, line, "at least 5 cups labelled 1 up to how many there are"));
+    }
+    Ok(labels)
+}
+
+/// A circle of cups as the label of the cup clockwise of each one, every label is a key of the filled map
+pub struct Cups {
+    next: DenseMap<u32>,
+    current: u32,
+    count: u32,
+}
+
+impl Cups {
+    /// `labels` in order, then the rest of the `count` cups numbered on from the largest label
+    pub fn new(labels: &[u32], count: u32) -> Cups {
+        let mut next = DenseMap::filled(count as usize + 1);
+        let mut cups = labels.iter().copied().chain(labels.len() as u32 + 1..=count);
+        let first = cups.next().expect("at least a cup");
+        let last = cups.fold(first, |previous, cup| {
+            next[previous as usize] = cup;
+            cup
+        });
+        next[last as usize] = first;
+        Cups { next, current: first, count }
+    }
+
+    fn after(&self, cup: u32) -> u32 {
+        self.next[cup as usize]
+    }
+
+    /// A move picks up the three cups after the current one, puts them back after the cup labelled one less,
+    /// skipping the picked ones and wrapping from the lowest label to the highest, and moves on by one cup
+    pub fn play(&mut self, moves: usize) {
+        for _ in 0..moves {
+            let first = self.after(self.current);
+            let second = self.after(first);
+            let third = self.after(second);
+            let mut destination = self.current;
+            loop {
+                destination = if destination == 1 { self.count } else { destination - 1 };
+                if destination != first && destination != second && destination != third {
+                    break;
+                }
+            }
+            self.next[self.current as usize] = self.after(third);
+            self.next[third as usize] = self.after(destination);
+            self.next[destination as usize] = first;
+            self.current = self.after(self.current);
+        }
+    }
+
+    /// Labels clockwise after `cup`, once round the circle without `cup` itself
+    pub fn clockwise(&self, cup: u32) -> impl Iterator<Item=u32> + '_ {
+        std::iter::successors(Some(self.after(cup)), move |&next| Some(self.after(next))).take_while(move |&next| next != cup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cups, labels};
+
+    #[test]
+    fn parsing_test() {
+        assert_eq!(labels("389125467\n").unwrap(), vec![3, 8, 9, 1, 2, 5, 4, 6, 7]);
+        assert_eq!(labels("38912546x").unwrap_err().to_string(), "line 1, column 9: expected a cup label from 1 to 9, found \"x\"");
+        assert_eq!(labels("3891254673").unwrap_err().expected, "a label not used before");
+        assert_eq!(labels("1234").unwrap_err().expected, "at least 5 cups labelled 1 up to how many there are");
+        assert!(labels("12346").is_err());
+    }
+
+    #[test]
+    fn test_from_example() {
+        let labels = labels("389125467").unwrap();
+        let mut cups = Cups::new(&labels, 9);
+        cups.play(10);
+        assert_eq!(cups.clockwise(1).map(|cup| cup.to_string()).collect::<String>(), "92658374");
+        cups.play(90);
+        assert_eq!(cups.clockwise(1).map(|cup| cup.to_string()).collect::<String>(), "67384529");
+        let cups = Cups::new(&labels, 20);
+        assert_eq!(cups.clockwise(7).take(3).collect::<Vec<_>>(), vec![10, 11, 12]);
+        assert_eq!(cups.clockwise(20).next(), Some(3));
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_rust::solution::print_answers;
+use aoc_rust::day23::Day23;
+
+fn main() {
+    print_answers::<Day23>();
+}
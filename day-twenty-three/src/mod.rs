@@ -0,0 +1,38 @@
+pub mod cups;
+
+use crate::error::{ParseError, NoAnswer};
+use crate::solution::Solution;
+use crate::generate::Rng;
+use rand::seq::SliceRandom;
+use cups::{Cups, labels};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    /// The puzzle's example
+    const INPUT: &'static str = "./day-twenty-three/cups.dat";
+    type Input = Vec<u32>;
+    type PartOne = String;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        labels(input)
+    }
+    fn part_one(labels: &Vec<u32>) -> Result<String, NoAnswer> {
+        let mut cups = Cups::new(labels, labels.len() as u32);
+        cups.play(100);
+        Ok(cups.clockwise(1).map(|cup| cup.to_string()).collect())
+    }
+    fn part_two(labels: &Vec<u32>) -> Result<u64, NoAnswer> {
+        let mut cups = Cups::new(labels, 1_000_000);
+        cups.play(10_000_000);
+        Ok(cups.clockwise(1).take(2).map(u64::from).product())
+    }
+    fn generate(rng: &mut Rng, _size: usize) -> String {
+        // The puzzle always has nine cups
+        let mut labels = (1..=9).map(|label: u32| label.to_string()).collect::<Vec<_>>();
+        labels.shuffle(rng);
+        labels.concat()
+    }
+}
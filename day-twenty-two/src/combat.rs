@@ -0,0 +1,169 @@
+use std::collections::{HashSet, VecDeque};
+use std::str::FromStr;
+use tracing::debug;
+use crate::error::{ParseError, number};
+
+pub type Deck = VecDeque<u8>;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Player {
+    One,
+    Two,
+}
+
+/// Both players' decks, top card first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decks {
+    pub one: Deck,
+    pub two: Deck,
+}
+
+/// Parses `section`, a slice of `source` that starts with the `header` line, as a deck
+fn deck(source: &str, section: &str, header: &str) -> Result<Deck, ParseError> {
+    match section.split_once('\n') {
+        Some((first, cards)) if first.trim_end() == header => {
+            cards.lines().map(str::trim).filter(|line| !line.is_empty()).map(|card| number(source, card)).collect()
+        }
+        _ => Err(ParseError::new(source, section.lines().next().unwrap_or(section), format!("`{}` and its cards", header))),
+    }
+}
+
+impl FromStr for Decks {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let end = &s[s.len()..];
+        let (one, two) = s.trim_start().split_once("\n\n")
+            .ok_or_else(|| ParseError::new(s, end, "a blank line between the two decks"))?;
+        let decks = Decks { one: deck(s, one, "Player 1:")?, two: deck(s, two.trim_start_matches('\n'), "Player 2:")? };
+        // Rounds can't be tied, and a recursive game settles early on the highest card, so every card differs
+        let mut dealt = HashSet::new();
+        if let Some(card) = decks.one.iter().chain(&decks.two).find(|&&card| !dealt.insert(card)) {
+            return Err(ParseError::new(s, end, format!("cards that are all different, {} is dealt twice", card)));
+        }
+        Ok(decks)
+    }
+}
+
+/// How a game went. The games and rounds are the ones played out, sub-games of a recursive one included; a sub-game
+/// settled by its highest card without playing counts for neither.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub winner: Player,
+    pub score: u64,
+    pub games: usize,
+    pub rounds: usize,
+}
+
+/// Sum of each card times its place counted from the bottom
+fn score(deck: &Deck) -> u64 {
+    deck.iter().rev().zip(1..).map(|(&card, place)| card as u64 * place).sum()
+}
+
+impl Decks {
+    /// Higher card takes both, until a player holds every card. The deals are distinct so no round ties, and should
+    /// the decks come back to an earlier round, which never happens with the puzzle's decks, player one wins.
+    pub fn combat(&self) -> Game {
+        let (mut one, mut two) = (self.one.clone(), self.two.clone());
+        let mut seen = HashSet::new();
+        let mut rounds = 0;
+        while !one.is_empty() && !two.is_empty() && seen.insert((one.clone(), two.clone())) {
+            rounds += 1;
+            let (a, b) = (one.pop_front().unwrap(), two.pop_front().unwrap());
+            match a > b {
+                true => one.extend([a, b]),
+                false => two.extend([b, a]),
+            }
+        }
+        let game = Decks::finish(one, two, 1, rounds);
+        debug!("Combat: {:?} wins with {} after {} rounds", game.winner, game.score, game.rounds);
+        game
+    }
+
+    /// Rounds are decided by a sub-game on copies of the top cards when both players hold at least as many cards
+    /// as they drew, any game whose decks come back to an earlier round goes to player one
+    pub fn recursive_combat(&self) -> Game {
+        let (mut one, mut two) = (self.one.clone(), self.two.clone());
+        let (mut games, mut rounds) = (0, 0);
+        recursive_game(&mut one, &mut two, true, &mut games, &mut rounds);
+        let game = Decks::finish(one, two, games, rounds);
+        debug!("Recursive combat: {:?} wins with {} after {} games and {} rounds", game.winner, game.score, game.games, game.rounds);
+        game
+    }
+
+    fn finish(one: Deck, two: Deck, games: usize, rounds: usize) -> Game {
+        match two.is_empty() {
+            true => Game { winner: Player::One, score: score(&one), games, rounds },
+            false if one.is_empty() => Game { winner: Player::Two, score: score(&two), games, rounds },
+            // Stopped on a repeat
+            false => Game { winner: Player::One, score: score(&one), games, rounds },
+        }
+    }
+}
+
+/// Plays a game of recursive combat on the decks, leaving them as they end, and tells who won
+fn recursive_game(one: &mut Deck, two: &mut Deck, top: bool, games: &mut usize, rounds: &mut usize) -> Player {
+    // Holding the highest card of a sub-game, player one can't lose it: all cards differ, so there are fewer of them
+    // than its value and it never starts a sub-game, it just beats whatever it meets. Player two never takes
+    // every card then and player one wins, by a repeat at worst. The top game needs its decks played out for the score.
+    if !top && one.iter().max() > two.iter().max() {
+        return Player::One;
+    }
+    *games += 1;
+    let mut seen = HashSet::new();
+    while !one.is_empty() && !two.is_empty() {
+        if !seen.insert((one.clone(), two.clone())) {
+            return Player::One;
+        }
+        *rounds += 1;
+        let (a, b) = (one.pop_front().unwrap(), two.pop_front().unwrap());
+        let winner = match one.len() >= a as usize && two.len() >= b as usize {
+            true => {
+                let mut one = one.iter().take(a as usize).copied().collect();
+                let mut two = two.iter().take(b as usize).copied().collect();
+                recursive_game(&mut one, &mut two, false, games, rounds)
+            }
+            false if a > b => Player::One,
+            false => Player::Two,
+        };
+        match winner {
+            Player::One => one.extend([a, b]),
+            Player::Two => two.extend([b, a]),
+        }
+    }
+    if one.is_empty() { Player::Two } else { Player::One }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decks, Game, Player};
+    use std::str::FromStr;
+
+    const EXAMPLE: &str = "Player 1:\n9\n2\n6\n3\n1\n\nPlayer 2:\n5\n8\n4\n7\n10";
+
+    #[test]
+    fn parsing_test() {
+        let decks = Decks::from_str(EXAMPLE).unwrap();
+        assert_eq!((decks.one.len(), decks.two.front()), (5, Some(&5)));
+        assert_eq!(Decks::from_str("Player 1:\n9\n\nPlayer 3:\n5").unwrap_err().to_string(),
+                   "line 4, column 1: expected `Player 2:` and its cards, found \"Player 3:\"");
+        assert_eq!(Decks::from_str("Player 1:\n9\n\nPlayer 2:\nx").unwrap_err().line, 5);
+        assert_eq!(Decks::from_str("Player 1:\n9\n\nPlayer 2:\n9").unwrap_err().expected, "cards that are all different, 9 is dealt twice");
+        assert!(Decks::from_str("Player 1:\n9").is_err());
+    }
+
+    #[test]
+    fn test_from_example() {
+        let decks = Decks::from_str(EXAMPLE).unwrap();
+        assert_eq!(decks.combat(), Game { winner: Player::Two, score: 306, games: 1, rounds: 29 });
+        let game = decks.recursive_combat();
+        assert_eq!((game.winner, game.score), (Player::Two, 291));
+        assert_eq!((game.games, game.rounds), (5, 29));
+        // The first round's sub-game of 9 and 4 against 3 goes to player one without being played
+        let settled = Decks::from_str("Player 1:\n2\n9\n4\n\nPlayer 2:\n1\n3\n5").unwrap().recursive_combat();
+        assert_eq!((settled.winner, settled.games, settled.rounds), (Player::One, 1, 9));
+        // Without the repeat rule this one would go on forever
+        let looping = Decks::from_str("Player 1:\n43\n19\n\nPlayer 2:\n2\n29\n14").unwrap();
+        assert_eq!(looping.recursive_combat().winner, Player::One);
+    }
+}
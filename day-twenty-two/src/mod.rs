@@ -0,0 +1,37 @@
+pub mod combat;
+
+use std::str::FromStr;
+use crate::error::{ParseError, NoAnswer};
+use crate::solution::Solution;
+use crate::generate::Rng;
+use rand::seq::SliceRandom;
+use combat::Decks;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    /// The puzzle's example
+    const INPUT: &'static str = "./day-twenty-two/decks.dat";
+    type Input = Decks;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Decks, ParseError> {
+        Decks::from_str(input)
+    }
+    fn part_one(decks: &Decks) -> Result<u64, NoAnswer> {
+        Ok(decks.combat().score)
+    }
+    fn part_two(decks: &Decks) -> Result<u64, NoAnswer> {
+        Ok(decks.recursive_combat().score)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Like the puzzle, cards 1 to 2n shuffled and dealt n each
+        let cards = size.clamp(2, 25);
+        let mut deck = (1..=2 * cards).collect::<Vec<_>>();
+        deck.shuffle(rng);
+        let hand = |cards: &[usize]| cards.iter().map(usize::to_string).collect::<Vec<_>>().join("\n");
+        format!("Player 1:\n{}\n\nPlayer 2:\n{}", hand(&deck[..cards]), hand(&deck[cards..]))
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_rust::solution::print_answers;
+use aoc_rust::day20::Day20;
+
+fn main() {
+    print_answers::<Day20>();
+}
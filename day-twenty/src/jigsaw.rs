@@ -0,0 +1,272 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use lazy_static::lazy_static;
+use regex::Regex;
+use tracing::debug;
+use crate::error::{ParseError, number};
+use crate::grid::{Grid, Point, Tile};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Pixel {
+    Calm,
+    Rough,
+}
+
+impl Tile for Pixel {
+    const EXPECTED: &'static str = "`#` or `.`";
+    fn from_char(c: char) -> Option<Pixel> {
+        match c {
+            '.' => Some(Pixel::Calm),
+            '#' => Some(Pixel::Rough),
+            _ => None
+        }
+    }
+    fn to_char(&self) -> char {
+        match self {
+            Pixel::Calm => '.',
+            Pixel::Rough => '#',
+        }
+    }
+}
+
+/// A square tile of the picture with its number, in the orientation it was received
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Piece {
+    pub id: u64,
+    pub image: Grid<Pixel>,
+}
+
+impl FromStr for Piece {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref HEADER_RE: Regex = Regex::new(r#"^Tile (?P<id>\d+):\s*$"#).unwrap();
+        }
+        let (header, image) = s.split_once('\n').unwrap_or((s, &s[s.len()..]));
+        let id = HEADER_RE.captures(header).map(|matched| matched.name("id").unwrap().as_str())
+            .ok_or_else(|| ParseError::new(s, header, "a header like `Tile 2311:`"))?;
+        let image = Grid::<Pixel>::from_str(image).map_err(|error| error.within(s, image))?;
+        if image.width() != image.height() {
+            return Err(ParseError::new(s, header, format!("a square tile, not {}x{}", image.width(), image.height())));
+        }
+        // Tile numbers fit in 16 bits so the product of four of them fits in 64
+        let id: u16 = number(s, id)?;
+        Ok(Piece { id: id as u64, image })
+    }
+}
+
+fn top(image: &Grid<Pixel>) -> Vec<Pixel> {
+    image.row(0).to_vec()
+}
+
+fn bottom(image: &Grid<Pixel>) -> Vec<Pixel> {
+    image.row(image.height() - 1).to_vec()
+}
+
+fn left(image: &Grid<Pixel>) -> Vec<Pixel> {
+    image.column(0).copied().collect()
+}
+
+fn right(image: &Grid<Pixel>) -> Vec<Pixel> {
+    image.column(image.width() - 1).copied().collect()
+}
+
+/// Pieces laid out row by row, each one as `(piece, orientation)`, placed one at a time and taken back
+/// when a later square can't be filled
+struct Assembly<'a> {
+    side: usize,
+    /// Every orientation of every piece, as in `Grid::orientations`
+    orientations: &'a [Vec<Grid<Pixel>>],
+    by_left: HashMap<Vec<Pixel>, Vec<(usize, usize)>>,
+    by_top: HashMap<Vec<Pixel>, Vec<(usize, usize)>>,
+    /// What fits the top left square, likely corners first
+    first: Vec<(usize, usize)>,
+    placed: Vec<(usize, usize)>,
+    used: Vec<bool>,
+}
+
+impl Assembly<'_> {
+    fn image(&self, (piece, orientation): (usize, usize)) -> &Grid<Pixel> {
+        &self.orientations[piece][orientation]
+    }
+
+    fn fill(&mut self) -> bool {
+        let at = self.placed.len();
+        if at == self.side * self.side {
+            return true;
+        }
+        let (x, y) = (at % self.side, at / self.side);
+        let above = if y > 0 { Some(bottom(self.image(self.placed[at - self.side]))) } else { None };
+        let candidates = match (x, &above) {
+            (0, Some(above)) => self.by_top.get(above).cloned().unwrap_or_default(),
+            (0, None) => self.first.clone(),
+            _ => self.by_left.get(&right(self.image(self.placed[at - 1]))).cloned().unwrap_or_default(),
+        };
+        for candidate in candidates {
+            if self.used[candidate.0] || above.as_ref().is_some_and(|above| top(self.image(candidate)) != *above) {
+                continue;
+            }
+            self.used[candidate.0] = true;
+            self.placed.push(candidate);
+            if self.fill() {
+                return true;
+            }
+            self.placed.pop();
+            self.used[candidate.0] = false;
+        }
+        false
+    }
+}
+
+/// Tiles separated by blank lines, fitted together into a square picture
+#[derive(Debug, Clone)]
+pub struct Jigsaw {
+    pub pieces: Vec<Piece>,
+    /// Each square of the picture as `(piece, orientation)`, the orientation indexes `Grid::orientations`
+    pub arrangement: Grid<(usize, usize)>,
+}
+
+impl FromStr for Jigsaw {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let end = &s[s.len()..];
+        let mut pieces: Vec<Piece> = Vec::new();
+        for block in s.split("\n\n").map(|block| block.trim_matches('\n')).filter(|block| !block.trim().is_empty()) {
+            let piece = Piece::from_str(block).map_err(|error| error.within(s, block))?;
+            if let Some(first) = pieces.first() {
+                if piece.image.width() != first.image.width() {
+                    let size = first.image.width();
+                    return Err(ParseError::new(s, block, format!("a {}x{} tile like the first one", size, size)));
+                }
+            }
+            if pieces.iter().any(|other| other.id == piece.id) {
+                return Err(ParseError::new(s, block, "a tile number not used before"));
+            }
+            pieces.push(piece);
+        }
+        let side = (0..).find(|side| side * side >= pieces.len()).unwrap();
+        if pieces.is_empty() || side * side != pieces.len() {
+            return Err(ParseError::new(s, end, format!("a square number of tiles, not {}", pieces.len())));
+        }
+        let arrangement = assemble(&pieces, side)
+            .ok_or_else(|| ParseError::new(s, end, "tiles whose borders fit together into a square"))?;
+        Ok(Jigsaw { pieces, arrangement })
+    }
+}
+
+/// Finds where each piece goes in a `side` by `side` square and how it's turned, so that touching borders match
+fn assemble(pieces: &[Piece], side: usize) -> Option<Grid<(usize, usize)>> {
+    let orientations = pieces.iter().map(|piece| piece.image.orientations()).collect::<Vec<_>>();
+    let mut sharing: HashMap<Vec<Pixel>, HashSet<usize>> = HashMap::new();
+    let mut by_left: HashMap<Vec<Pixel>, Vec<(usize, usize)>> = HashMap::new();
+    let mut by_top: HashMap<Vec<Pixel>, Vec<(usize, usize)>> = HashMap::new();
+    for (piece, images) in orientations.iter().enumerate() {
+        for (orientation, image) in images.iter().enumerate() {
+            // The top rows of all 8 orientations are every border read both ways
+            sharing.entry(top(image)).or_default().insert(piece);
+            by_left.entry(left(image)).or_default().push((piece, orientation));
+            by_top.entry(top(image)).or_default().push((piece, orientation));
+        }
+    }
+    // A corner turned the right way has borders that no other piece has on its top and left
+    let mut first = (0..pieces.len()).flat_map(|piece| (0..8).map(move |orientation| (piece, orientation))).collect::<Vec<_>>();
+    first.sort_by_key(|&(piece, orientation)| {
+        let image = &orientations[piece][orientation];
+        sharing[&top(image)].len() + sharing[&left(image)].len() > 2
+    });
+    let mut assembly = Assembly { side, orientations: &orientations, by_left, by_top, first, placed: Vec::new(), used: vec![false; pieces.len()] };
+    match assembly.fill() {
+        true => {
+            debug!("Assembled {} tiles into a {}x{} square", pieces.len(), side, side);
+            Some(Grid::from_rows(side, assembly.placed.chunks(side).map(<[_]>::to_vec).collect()))
+        }
+        false => None,
+    }
+}
+
+impl Jigsaw {
+    /// Product of the numbers of the four corner tiles
+    pub fn corners(&self) -> u64 {
+        let last = self.arrangement.width() - 1;
+        [(0, 0), (last, 0), (0, last), (last, last)].iter().map(|&corner| self.pieces[self.arrangement[corner].0].id).product()
+    }
+
+    /// The assembled picture without the borders of the tiles
+    pub fn picture(&self) -> Grid<Pixel> {
+        let inner = self.pieces[0].image.width().saturating_sub(2);
+        let images = self.arrangement.map(|&(piece, orientation)| {
+            self.pieces[piece].image.orientations().swap_remove(orientation).crop((1, 1), inner, inner)
+        });
+        let rows = images.rows()
+            .flat_map(|row| (0..inner).map(move |y| row.iter().flat_map(|image| image.row(y).iter().copied()).collect()))
+            .collect();
+        Grid::from_rows(images.width() * inner, rows)
+    }
+}
+
+pub const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+/// Squares of a sea monster, relative to the top left corner of the pattern
+fn sea_monster() -> Vec<Point> {
+    SEA_MONSTER.iter().enumerate()
+        .flat_map(|(y, line)| line.char_indices().filter(|&(_, c)| c == '#').map(move |(x, _)| (x, y)))
+        .collect()
+}
+
+/// Squares taken up by sea monsters in `picture` as it is, monsters can overlap
+pub fn sea_monsters(picture: &Grid<Pixel>) -> HashSet<Point> {
+    let monster = sea_monster();
+    let (width, height) = (SEA_MONSTER[0].len(), SEA_MONSTER.len());
+    let mut squares = HashSet::new();
+    for y in 0..(picture.height() + 1).saturating_sub(height) {
+        for x in 0..(picture.width() + 1).saturating_sub(width) {
+            if monster.iter().all(|&(dx, dy)| picture[(x + dx, y + dy)] == Pixel::Rough) {
+                squares.extend(monster.iter().map(|&(dx, dy)| (x + dx, y + dy)));
+            }
+        }
+    }
+    squares
+}
+
+/// Rough water that's not part of a sea monster, with the picture turned the way that shows them
+pub fn roughness(picture: &Grid<Pixel>) -> usize {
+    let rough = picture.iter().filter(|(_, &pixel)| pixel == Pixel::Rough).count();
+    let monsters = picture.orientations().iter().map(|picture| sea_monsters(picture).len()).max().unwrap_or(0);
+    rough - monsters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Jigsaw, Piece, roughness, sea_monsters};
+    use std::fs;
+    use std::str::FromStr;
+
+    #[test]
+    fn parsing_test() {
+        let piece = Piece::from_str("Tile 7:\n#.\n..").unwrap();
+        assert_eq!((piece.id, piece.image.width()), (7, 2));
+        assert_eq!(Piece::from_str("Tile x:\n#.\n..").unwrap_err().expected, "a header like `Tile 2311:`");
+        assert_eq!(Piece::from_str("Tile 7:\n#.\n..\n..").unwrap_err().expected, "a square tile, not 2x3");
+        assert_eq!(Jigsaw::from_str("Tile 7:\n#.\n..\n\nTile 8:\n#.\n.x").unwrap_err().to_string(),
+                   "line 7, column 2: expected `#` or `.`, found \"x\"");
+        assert_eq!(Jigsaw::from_str("Tile 7:\n#.\n..\n\nTile 8:\n#.\n..").unwrap_err().expected, "a square number of tiles, not 2");
+        assert_eq!(Jigsaw::from_str("Tile 7:\n#.\n..\n\nTile 7:\n#.\n..").unwrap_err().expected, "a tile number not used before");
+        assert!(Jigsaw::from_str("").is_err());
+    }
+
+    #[test]
+    fn test_from_example() {
+        let jigsaw = Jigsaw::from_str(&fs::read_to_string("./day-twenty/tiles.dat").unwrap()).unwrap();
+        assert_eq!(jigsaw.corners(), 20899048083289);
+        let picture = jigsaw.picture();
+        assert_eq!((picture.width(), picture.height()), (24, 24));
+        assert_eq!(picture.orientations().iter().map(|picture| sea_monsters(picture).len() / 15).max(), Some(2));
+        assert_eq!(roughness(&picture), 273);
+    }
+}
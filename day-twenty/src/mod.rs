@@ -0,0 +1,79 @@
+pub mod jigsaw;
+
+use std::collections::HashSet;
+use std::str::FromStr;
+use crate::error::{ParseError, NoAnswer};
+use crate::grid::Grid;
+use crate::solution::Solution;
+use crate::generate::{self, Rng};
+use rand::Rng as _;
+use rand::seq::SliceRandom;
+use jigsaw::{Jigsaw, Pixel, roughness};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    /// The puzzle's example, nine tiles hiding two sea monsters
+    const INPUT: &'static str = "./day-twenty/tiles.dat";
+    type Input = Jigsaw;
+    type PartOne = u64;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Jigsaw, ParseError> {
+        Jigsaw::from_str(input)
+    }
+    fn part_one(jigsaw: &Jigsaw) -> Result<u64, NoAnswer> {
+        Ok(jigsaw.corners())
+    }
+    fn part_two(jigsaw: &Jigsaw) -> Result<usize, NoAnswer> {
+        Ok(roughness(&jigsaw.picture()))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Neighbouring 10x10 tiles are cut from one random picture overlapping by a row or column, so their
+        // borders match, sea monsters are drawn over the inside and every tile is turned some random way
+        let side = (size / 3).clamp(3, 12);
+        let squares = 9 * side + 1;
+        let mut picture = Grid::<Pixel>::from_str(&generate::character_map(rng, squares, squares, ('#', 0.3), '.')).unwrap();
+        let monster = jigsaw::SEA_MONSTER;
+        let inner = 8 * side;
+        for _ in 0..rng.gen_range(1..=side) {
+            let (x, y) = (rng.gen_range(0..=inner - monster[0].len()), rng.gen_range(0..=inner - monster.len()));
+            for (dy, line) in monster.iter().enumerate() {
+                for (dx, _) in line.char_indices().filter(|&(_, c)| c == '#') {
+                    // Inside square `i` of the picture is square `i + 1` of tile `i / 8`, tile `t` starts at `9t`
+                    let (x, y) = (x + dx, y + dy);
+                    picture[(x / 8 * 9 + 1 + x % 8, y / 8 * 9 + 1 + y % 8)] = Pixel::Rough;
+                }
+            }
+        }
+        // Every border appears on exactly the two tiles it separates, like in the puzzle: a middle pixel of a
+        // border that clashes with another one, either way round, is switched until none do
+        let borders = (0..=side).flat_map(|line| (0..side).flat_map(move |tile| {
+            let along = (0..10).map(move |step| 9 * tile + step);
+            [along.clone().map(|x| (x, 9 * line)).collect::<Vec<_>>(), along.map(|y| (9 * line, y)).collect()]
+        })).collect::<Vec<_>>();
+        loop {
+            let mut seen = HashSet::new();
+            let clash = borders.iter().find(|border| {
+                let pixels = border.iter().map(|&point| picture[point]).collect::<Vec<_>>();
+                let reversed = pixels.iter().rev().copied().collect::<Vec<_>>();
+                !seen.insert(pixels.min(reversed))
+            });
+            match clash {
+                Some(border) => {
+                    let point = border[rng.gen_range(1..9)];
+                    picture[point] = if picture[point] == Pixel::Rough { Pixel::Calm } else { Pixel::Rough };
+                }
+                None => break,
+            }
+        }
+        let ids = generate::distinct(rng, &(1000..10000).collect::<Vec<u64>>(), side * side);
+        let mut tiles = ids.iter().enumerate().map(|(index, id)| {
+            let tile = picture.crop((index % side * 9, index / side * 9), 10, 10);
+            format!("Tile {}:\n{}", id, tile.orientations().swap_remove(rng.gen_range(0..8)))
+        }).collect::<Vec<_>>();
+        tiles.shuffle(rng);
+        tiles.join("\n\n")
+    }
+}
@@ -0,0 +1,68 @@
+pub mod passport;
+
+use crate::error::{ParseError, NoAnswer};
+use crate::solution::Solution;
+use crate::generate::{self, Rng};
+use rand::Rng as _;
+use rand::seq::SliceRandom;
+use passport::{PassportValidator, SimpleEntryValidator, RequiringValidator, check_entries};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = "./day_four/passports.dat";
+    type Input = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        check_entries(input)?;
+        Ok(input.to_string())
+    }
+    fn part_one(passports: &String) -> Result<usize, NoAnswer> {
+        Ok(PassportValidator { entry_validator: Box::new(SimpleEntryValidator {}) }.validate(passports))
+    }
+    fn part_two(passports: &String) -> Result<usize, NoAnswer> {
+        Ok(PassportValidator { entry_validator: Box::new(RequiringValidator {}) }.validate(passports))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+        const HEX: &[u8] = b"0123456789abcdef";
+        const DIGITS: &[u8] = b"0123456789";
+        // Roughly one in ten values breaks the part two rules and one in seven passports misses a field
+        (0..size).map(|_| {
+            let mut fields = vec![
+                format!("byr:{}", if rng.gen_bool(0.9) { rng.gen_range(1920..=2002) } else { rng.gen_range(1900..1920) }),
+                format!("iyr:{}", if rng.gen_bool(0.9) { rng.gen_range(2010..=2020) } else { rng.gen_range(2000..2010) }),
+                format!("eyr:{}", if rng.gen_bool(0.9) { rng.gen_range(2020..=2030) } else { rng.gen_range(2031..2040) }),
+                match rng.gen_range(0..10) {
+                    0 => format!("hgt:{}", rng.gen_range(100..200)),
+                    1..=5 => format!("hgt:{}cm", rng.gen_range(150..=193)),
+                    _ => format!("hgt:{}in", rng.gen_range(59..=76)),
+                },
+                format!("hcl:{}{}", if rng.gen_bool(0.9) { "#" } else { "" }, generate::word(rng, HEX, 6)),
+                format!("ecl:{}", if rng.gen_bool(0.9) { EYE_COLORS.choose(rng).unwrap() } else { "wat" }),
+                {
+                    let length = if rng.gen_bool(0.9) { 9 } else { 8 };
+                    format!("pid:{}", generate::word(rng, DIGITS, length))
+                },
+            ];
+            if rng.gen_bool(0.15) {
+                fields.remove(rng.gen_range(0..fields.len()));
+            }
+            if rng.gen_bool(0.5) {
+                fields.push(format!("cid:{}", rng.gen_range(100..350)));
+            }
+            fields.shuffle(rng);
+            let mut passport = String::new();
+            for (index, field) in fields.iter().enumerate() {
+                if index > 0 {
+                    passport.push(if rng.gen_bool(0.7) { ' ' } else { '\n' });
+                }
+                passport.push_str(field);
+            }
+            passport
+        }).collect::<Vec<_>>().join("\n\n")
+    }
+}
@@ -0,0 +1,67 @@
+use tracing::debug;
+use crate::grid::{Grid, Tile};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Square {
+    Open,
+    Tree,
+}
+
+impl Tile for Square {
+    const EXPECTED: &'static str = "`#` or `.`";
+    fn from_char(c: char) -> Option<Square> {
+        match c {
+            '.' => Some(Square::Open),
+            '#' => Some(Square::Tree),
+            _ => None
+        }
+    }
+    fn to_char(&self) -> char {
+        match self {
+            Square::Open => '.',
+            Square::Tree => '#',
+        }
+    }
+}
+
+/// Slice of the slope, it repeats itself to the right
+pub type Map = Grid<Square>;
+
+#[derive(Debug, PartialEq)]
+pub struct Velocity {
+    pub x: usize,
+    pub y: usize,
+}
+
+pub fn traverse(map: &Map, vel: &Velocity) -> usize {
+    let tree_count = (0..map.height()).step_by(vel.y).enumerate()
+        .filter(|&(step, y)| *map.get_wrapping((step * vel.x, y)) == Square::Tree)
+        .count();
+    debug!("With velocity {:?} Trees encountered: {}", vel, tree_count);
+    tree_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Map, Velocity, traverse};
+    use std::str::FromStr;
+
+    #[test]
+    fn example_slope() {
+        let input = r#"..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#"#;
+        let map = Map::from_str(input).unwrap();
+        assert_eq!(map.to_string(), input);
+        assert_eq!(traverse(&map, &Velocity { x: 3, y: 1 }), 7);
+        assert_eq!(traverse(&map, &Velocity { x: 1, y: 2 }), 2);
+    }
+}
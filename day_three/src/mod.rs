@@ -0,0 +1,32 @@
+pub mod map;
+
+use std::str::FromStr;
+use crate::error::{ParseError, NoAnswer};
+use crate::solution::Solution;
+use crate::generate::{self, Rng};
+use map::{Map, Velocity, traverse};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = "./day_three/map_slice.dat";
+    type Input = Map;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Map::from_str(input)
+    }
+    fn part_one(map: &Map) -> Result<usize, NoAnswer> {
+        Ok(traverse(map, &Velocity { x: 3, y: 1 }))
+    }
+    fn part_two(map: &Map) -> Result<usize, NoAnswer> {
+        Ok([(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)].iter()
+            .map(|&(x, y)| traverse(map, &Velocity { x, y }))
+            .product())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::character_map(rng, 31, size.max(1), ('#', 0.25), '.')
+    }
+}
@@ -0,0 +1,38 @@
+pub mod password;
+
+use crate::error::{ParseError, NoAnswer, parse_lines};
+use crate::solution::Solution;
+use crate::generate::{self, Rng};
+use rand::Rng as _;
+use rand::seq::SliceRandom;
+use password::{PasswordPolicy, read_single};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = "./day_two/input.dat";
+    type Input = Vec<(PasswordPolicy, String)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, read_single)
+    }
+    fn part_one(polcied_pass: &Self::Input) -> Result<usize, NoAnswer> {
+        Ok(polcied_pass.iter().filter(|(policy, pass)| policy.sled_rentel_place_check(pass)).count())
+    }
+    fn part_two(polcied_pass: &Self::Input) -> Result<usize, NoAnswer> {
+        Ok(polcied_pass.iter().filter(|(policy, pass)| policy.toboggan_chek(pass)).count())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const LETTERS: &[u8] = b"abcde";
+        (0..size).map(|_| {
+            let length = rng.gen_range(1..=20);
+            let password = generate::word(rng, LETTERS, length);
+            let min = rng.gen_range(1..=length);
+            let max = rng.gen_range(min..=length);
+            format!("{}-{} {}: {}", min, max, *LETTERS.choose(rng).unwrap() as char, password)
+        }).collect::<Vec<_>>().join("\n")
+    }
+}
@@ -0,0 +1,123 @@
+use std::str::FromStr;
+use crate::error::{ParseError, number};
+
+#[derive(Debug, PartialEq)]
+pub struct PasswordPolicy {
+    min: usize,
+    max: usize,
+    leter: char,
+}
+impl PasswordPolicy {
+    pub fn sled_rentel_place_check(&self, password: &str) -> bool {
+        let range = self.min..=self.max;
+        range.contains(&password.matches(self.leter).count())
+    }
+    /// Positions count from 1, a position outside of the password doesn't hold the letter
+    pub fn toboggan_chek(&self, password: &str) -> bool {
+        let holds_letter = |position: usize| position.checked_sub(1)
+            .and_then(|index| password.chars().nth(index)) == Some(self.leter);
+        holds_letter(self.min) != holds_letter(self.max)
+    }
+}
+impl FromStr for PasswordPolicy {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (range, letter) = s.split_once(' ')
+            .ok_or_else(|| ParseError::new(s, s, "a policy like `1-3 a`"))?;
+        let (min, max) = range.split_once('-')
+            .ok_or_else(|| ParseError::new(s, range, "a range like `1-3`"))?;
+        Ok(PasswordPolicy {
+            min: number(s, min)?,
+            max: number(s, max)?,
+            leter: letter.parse::<char>().map_err(|_| ParseError::new(s, letter, "a single letter"))?,
+        })
+    }
+}
+
+pub fn read_single(str: &str) -> Result<(PasswordPolicy, String), ParseError> {
+    match str.split_once(": ") {
+        Some((policy, password)) => Ok((PasswordPolicy::from_str(policy)?, String::from(password))),
+        None => Err(ParseError::new(str, str, "`<policy>: <password>`")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PasswordPolicy, read_single};
+    use std::str::FromStr;
+    use crate::error::ParseError;
+
+    #[test]
+    fn load() {
+        let input = [("7-8 x", PasswordPolicy { min: 7, max: 8, leter: 'x' }),
+            ("9-11 k", PasswordPolicy { min: 9, max: 11, leter: 'k' }),
+            ("8-12 g", PasswordPolicy { min: 8, max: 12, leter: 'g' }),
+            ("6-9 v", PasswordPolicy { min: 6, max: 9, leter: 'v' })];
+        for (str, policy) in input.iter() {
+            assert_eq!(PasswordPolicy::from_str(str).unwrap(), *policy)
+        }
+    }
+
+    #[test]
+    fn load_single() {
+        {
+            let input = "7-8 x";
+            assert_eq!(PasswordPolicy::from_str(input).unwrap(), PasswordPolicy { min: 7, max: 8, leter: 'x' })
+        }
+        {
+            let input = "9-11 k";
+            assert_eq!(PasswordPolicy::from_str(input).unwrap(), PasswordPolicy { min: 9, max: 11, leter: 'k' })
+        }
+    }
+
+    #[test]
+    fn read_single_test() {
+        let input = ["4-12 h: mcwvwwphwwbc",
+            "6-11 g: gqgggvggggh",
+            "9-15 x: xxxxxxxxxxxxxxsx",
+            "16-18 t: rmqqtbtvttsdtjvbttl",
+            "9-20 f: cllnvlfkfrwzpqxwqgnn",
+            "9-18 v: vvvvvvvvzvvvvvvzvxvv"];
+        assert_eq!(read_single(input[0]), Ok((PasswordPolicy { min: 4, max: 12, leter: 'h' }, String::from("mcwvwwphwwbc"))))
+    }
+    #[test]
+    fn verify_password() {
+        let input = ["4-12 h: mcwvwwphhhh",
+            "6-9 g: gqgggvggggh"];
+        for str in input.iter()
+        {
+            let (policy,pass) = read_single(str).unwrap();
+            assert!(policy.sled_rentel_place_check(&pass));
+        }
+    }
+    #[test]
+    fn negative_verify_password() {
+        let input = ["4-12 h: mcwvwwphhh",
+            "6-9 g: gqgggvggggggh"];
+        for str in input.iter()
+        {
+            let (policy,pass) = read_single(str).unwrap();
+            assert!(!policy.sled_rentel_place_check(&pass));
+        }
+    }
+
+    #[test]
+    fn positions_outside_password() {
+        let check = |line: &str| {
+            let (policy, pass) = read_single(line).unwrap();
+            policy.toboggan_chek(&pass)
+        };
+        assert!(check("1-3 a: abcde"));
+        assert!(!check("2-9 b: cdefg"));
+        assert!(check("0-2 é: aé"));
+        assert!(!check("0-40 a: a"));
+        assert!(!read_single("1-18446744073709551615 a: a").unwrap().0.sled_rentel_place_check("b"));
+    }
+
+    #[test]
+    fn malformed_policy() {
+        let error = read_single("4-x h: mcwvwwphhh").unwrap_err();
+        assert_eq!(error, ParseError { line: 1, column: 3, text: "x".to_string(), expected: "a number".to_string() });
+        assert_eq!(read_single("4-12 h mcwvwwphhh").unwrap_err().expected, "`<policy>: <password>`");
+    }
+}
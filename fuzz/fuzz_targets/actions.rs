@@ -0,0 +1,9 @@
+#![no_main]
+//! Navigation actions
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day12::Day12;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day12::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+//! Bag rules. Only parsed, rules that hold each other in a loop have no answer
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day7::Day7;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day7::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+//! Boarding passes
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day5::Day5;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day5::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+//! Bus notes. Only parsed, part two needs coprime bus IDs
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day13::Day13;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day13::parse(input);
+});
@@ -0,0 +1,11 @@
+#![no_main]
+//! Cup labels, every circle that parses plays its hundred moves. The million cups of part two take too long to fuzz.
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day23::Day23;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    if let Ok(labels) = Day23::parse(input) {
+        let _ = Day23::part_one(&labels);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+//! Two decks, every deal that parses is played out both ways.
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day22::Day22;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    if let Ok(decks) = Day22::parse(input) {
+        let _ = Day22::part_one(&decks);
+        let _ = Day22::part_two(&decks);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+//! Docking programs, version 1 of the decoder runs on any of them. Version 2 may have to write 2^36 addresses
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day14::Day14;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    if let Ok(program) = Day14::parse(input) {
+        let _ = Day14::part_one(&program);
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+//! Homework lines, every line that passes the check evaluates both ways.
+//! Lines of up to fifteen digits stay well within `i64` even summed up, longer ones are only checked.
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day18::Day18;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    if let Ok(homework) = Day18::parse(input) {
+        if homework.iter().all(|line| line.chars().filter(char::is_ascii_digit).count() <= 15) {
+            let _ = Day18::part_one(&homework);
+            let _ = Day18::part_two(&homework);
+        }
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+//! Ingredient lists, every well formed list answers both parts.
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day21::Day21;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    if let Ok(foods) = Day21::parse(input) {
+        let _ = Day21::part_one(&foods);
+        let _ = Day21::part_two(&foods);
+    }
+});
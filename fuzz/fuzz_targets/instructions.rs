@@ -0,0 +1,9 @@
+#![no_main]
+//! Handheld console programs. Only parsed, a program can jump anywhere
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day8::Day8;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day8::parse(input);
+});
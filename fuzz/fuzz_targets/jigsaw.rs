@@ -0,0 +1,15 @@
+#![no_main]
+//! Tiles, every set that assembles gives a corner product and a picture to search.
+//! Parsing backtracks through the ways tiles with clashing borders fit, so only up to four tiles are parsed.
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day20::Day20;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    if input.matches("Tile").count() <= 4 {
+        if let Ok(jigsaw) = Day20::parse(input) {
+            let _ = Day20::part_one(&jigsaw);
+            let _ = Day20::part_two(&jigsaw);
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+//! Rules and messages, every grammar that parses matches both ways without looping forever.
+//! The matcher recurses once per character at most, messages are capped so the stack stays shallow.
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day19::Day19;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    if let Ok(messages) = Day19::parse(input) {
+        if messages.messages.iter().all(|message| message.len() <= 200) {
+            let _ = Day19::part_one(&messages);
+            let _ = Day19::part_two(&messages);
+        }
+    }
+});
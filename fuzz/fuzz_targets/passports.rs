@@ -0,0 +1,12 @@
+#![no_main]
+//! Passport batches, every field of a checked batch can be validated
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day4::Day4;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    if let Ok(passports) = Day4::parse(input) {
+        let _ = Day4::part_one(&passports);
+        let _ = Day4::part_two(&passports);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+//! Password policies, a parsed policy has to be checkable against its password whatever the positions
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day2::Day2;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    if let Ok(passwords) = Day2::parse(input) {
+        let _ = Day2::part_one(&passwords);
+        let _ = Day2::part_two(&passwords);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+//! Initial cube layers, printing the pocket dimension works even without active cubes.
+//! Cycles aren't run, a big layer takes far too long in four dimensions.
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day17::Day17;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    if let Ok((pocket, pocket_4d)) = Day17::parse(input) {
+        let _ = pocket.to_string();
+        let _ = pocket_4d.to_string();
+    }
+});
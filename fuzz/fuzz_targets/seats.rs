@@ -0,0 +1,9 @@
+#![no_main]
+//! Seat layouts
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day11::Day11;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    let _ = Day11::parse(input);
+});
@@ -0,0 +1,11 @@
+#![no_main]
+//! Ticket notes, scanning for invalid values works on any of them
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day16::Day16;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    if let Ok(tickets) = Day16::parse(input) {
+        let _ = Day16::part_one(&tickets);
+    }
+});
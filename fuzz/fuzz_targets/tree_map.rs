@@ -0,0 +1,12 @@
+#![no_main]
+//! Tree maps, any rectangle of `.` and `#` can be traversed
+use libfuzzer_sys::fuzz_target;
+use aoc_rust::day3::Day3;
+use aoc_rust::solution::Solution;
+
+fuzz_target!(|input: &str| {
+    if let Ok(map) = Day3::parse(input) {
+        let _ = Day3::part_one(&map);
+        let _ = Day3::part_two(&map);
+    }
+});
@@ -0,0 +1,40 @@
+/* C interface of the Advent of Code 2020 solvers, built by `cargo build --release` as
+ * target/release/libaoc_rust.so (libaoc_rust.dylib on macOS, aoc_rust.dll on Windows). */
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define AOC_ABI_VERSION 1
+
+typedef enum aoc_status {
+    AOC_OK = 0,
+    AOC_NO_SUCH_DAY = 1,
+    AOC_NO_SUCH_PART = 2,
+    AOC_NOT_UTF8 = 3,
+    AOC_MALFORMED_INPUT = 4, /* also input the part has no answer for */
+    AOC_PANICKED = 5, /* a bug in the solver */
+    AOC_NULL_ARGUMENT = 6,
+} aoc_status;
+
+/* AOC_ABI_VERSION of the loaded library */
+uint32_t aoc_abi_version(void);
+
+/* Solves `part` (1 or 2) of `day` for the `length` bytes of UTF-8 text at `input`, no terminating NUL needed.
+ * Unless `answer` is NULL, it is set to a new NUL-terminated string: the answer on AOC_OK, what went wrong otherwise.
+ * Release it with aoc_string_free. */
+aoc_status aoc_solve(uint8_t day, uint8_t part, const uint8_t *input, size_t length, char **answer);
+
+/* Releases a string set by aoc_solve, NULL is ignored */
+void aoc_string_free(char *string);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,157 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use crate::day11::Day11;
+use crate::day17::Day17;
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+/// A generation of a simulation, drawn with the characters of the day's input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub title: String,
+    pub text: String,
+}
+
+/// Every generation of `part` of a day with a simulation, days 11 and 17 have one
+pub fn frames(day: u8, part: u8, input: &str) -> Option<Result<Vec<Frame>, ParseError>> {
+    match day {
+        11 => Some(Day11::parse(input).map(|map| Day11::frames(&map, part))),
+        17 => Some(Day17::parse(input).map(|pockets| Day17::frames(&pockets, part))),
+        _ => None,
+    }
+}
+
+/// Draws each frame over the previous one, `delay` apart
+pub fn play(frames: &[Frame], delay: Duration) -> io::Result<()> {
+    let mut out = io::stdout().lock();
+    for frame in frames {
+        // Clear the screen and move to its top left corner
+        write!(out, "\x1b[2J\x1b[H{}\n{}\n", frame.title, frame.text)?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Image {
+    /// Color
+    Ppm,
+    /// Grayscale
+    Pgm,
+}
+
+/// Colors of the characters frames are drawn with, any other character is background
+const PALETTE: [(char, [u8; 3]); 4] = [('#', [192, 32, 32]), ('L', [48, 160, 64]), ('.', [224, 224, 224]), (' ', [255, 255, 255])];
+const BACKGROUND: u8 = 3;
+
+/// Every frame as palette indices, `scale` by `scale` pixels a character, all as large as the largest frame.
+/// Returns the width and height too.
+fn raster(frames: &[Frame], scale: usize) -> (usize, usize, Vec<Vec<u8>>) {
+    let columns = frames.iter().flat_map(|frame| frame.text.lines().map(|line| line.chars().count())).max().unwrap_or(0);
+    let rows = frames.iter().map(|frame| frame.text.lines().count()).max().unwrap_or(0);
+    let (width, height) = (columns.max(1) * scale, rows.max(1) * scale);
+    let pixels = frames.iter().map(|frame| {
+        let mut pixels = vec![BACKGROUND; width * height];
+        for (row, line) in frame.text.lines().enumerate() {
+            for (column, c) in line.chars().enumerate() {
+                let color = PALETTE.iter().position(|&(drawn, _)| drawn == c).map_or(BACKGROUND, |index| index as u8);
+                for y in row * scale..(row + 1) * scale {
+                    pixels[y * width + column * scale..y * width + (column + 1) * scale].fill(color);
+                }
+            }
+        }
+        pixels
+    }).collect();
+    (width, height, pixels)
+}
+
+fn gray([r, g, b]: [u8; 3]) -> u8 {
+    ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000) as u8
+}
+
+/// Saves each frame into `dir` as a `frame-NNNN.ppm` or `.pgm` image, returns the files written
+pub fn export(frames: &[Frame], dir: &Path, image: Image, scale: usize) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let (width, height, pixels) = raster(frames, scale.max(1));
+    let (magic, extension) = match image {
+        Image::Ppm => ("P6", "ppm"),
+        Image::Pgm => ("P5", "pgm"),
+    };
+    let mut written = Vec::new();
+    for (index, pixels) in pixels.iter().enumerate() {
+        let path = dir.join(format!("frame-{:04}.{}", index, extension));
+        let mut file = BufWriter::new(File::create(&path)?);
+        write!(file, "{}\n{} {}\n255\n", magic, width, height)?;
+        for &color in pixels {
+            let rgb = PALETTE[color as usize].1;
+            match image {
+                Image::Ppm => file.write_all(&rgb)?,
+                Image::Pgm => file.write_all(&[gray(rgb)])?,
+            }
+        }
+        file.flush()?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Saves the frames as a looping GIF at `path`, `delay` apart
+#[cfg(feature = "gif")]
+pub fn export_gif(frames: &[Frame], path: &Path, scale: usize, delay: Duration) -> io::Result<()> {
+    use std::convert::TryFrom;
+    let (width, height, pixels) = raster(frames, scale.max(1));
+    let too_large = |_| io::Error::new(io::ErrorKind::InvalidInput, "frames larger than 65535 pixels");
+    let (width, height) = (u16::try_from(width).map_err(too_large)?, u16::try_from(height).map_err(too_large)?);
+    let palette = PALETTE.iter().flat_map(|(_, rgb)| rgb.iter().copied()).collect::<Vec<_>>();
+    let mut encoder = gif::Encoder::new(BufWriter::new(File::create(path)?), width, height, &palette)
+        .map_err(io::Error::other)?;
+    encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+    for pixels in pixels {
+        // The delay is in hundredths of a second
+        let delay = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+        let frame = gif::Frame { width, height, delay, buffer: pixels.into(), ..gif::Frame::default() };
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{export, frames, raster, Frame, Image};
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn exports_padded_frames() {
+        let frames = vec![Frame { title: String::new(), text: "#L\n.".to_string() }, Frame { title: String::new(), text: "x".to_string() }];
+        let (width, height, pixels) = raster(&frames, 2);
+        assert_eq!((width, height), (4, 4));
+        assert_eq!(pixels[0], vec![0, 0, 1, 1, 0, 0, 1, 1, 2, 2, 3, 3, 2, 2, 3, 3]);
+        assert_eq!(pixels[1], vec![3; 16]);
+
+        let dir = env::temp_dir().join(format!("aoc-animate-test-{}", std::process::id()));
+        let written = export(&frames, &dir, Image::Pgm, 1).unwrap();
+        assert_eq!(written.len(), 2);
+        assert_eq!(fs::read(&written[0]).unwrap(), b"P5\n2 2\n255\n\x4f\x73\xe0\xff");
+        let written = export(&frames, &dir, Image::Ppm, 1).unwrap();
+        assert_eq!(fs::read(&written[1]).unwrap().len(), "P6\n2 2\n255\n".len() + 12);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn simulations() {
+        let seats = frames(11, 1, "L.LL.LL.LL\nLLLLLLL.LL\nL.L.L..L..\nLLLL.LL.LL\nL.LL.LL.LL\nL.LLLLL.LL\n..L.L.....\nLLLLLLLLLL\nL.LLLLLL.L\nL.LLLLL.LL").unwrap().unwrap();
+        assert_eq!(seats.len(), 6);
+        assert_eq!(seats[5].title, "Day 11 part 1, round 5: 37 occupied seats");
+        let cubes = frames(17, 2, ".#.\n..#\n###").unwrap().unwrap();
+        assert_eq!(cubes.len(), 7);
+        assert!(cubes[6].title.starts_with("Day 17 part 2, cycle 6: 848 active cubes"));
+        assert!(frames(17, 1, "x").unwrap().is_err());
+        assert!(frames(1, 1, "").is_none());
+    }
+}
@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use crate::error::{ParseError, parse_lines, number};
+
+/// Checked-in answers, relative to the repository root
+pub const ANSWERS: &str = "answers.txt";
+
+/// Known answer for one part of a day, solved from `input`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub input: PathBuf,
+    pub answer: String,
+}
+
+impl Answer {
+    /// Parses a `<day> <part> <input> <answer>` line
+    fn parse(line: &str) -> Result<Answer, ParseError> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        match fields[..] {
+            [day, part, input, answer] => Ok(Answer {
+                day: number(line, day)?,
+                part: match part {
+                    "1" | "2" => number(line, part)?,
+                    _ => return Err(ParseError::new(line, part, "part 1 or 2")),
+                },
+                input: PathBuf::from(input),
+                answer: answer.to_string(),
+            }),
+            _ => Err(ParseError::new(line, line.trim(), "an answer like `<day> <part> <input> <answer>`")),
+        }
+    }
+}
+
+/// Parses an answers file, blank lines and lines starting with `#` are skipped
+pub fn parse(text: &str) -> Result<Vec<Answer>, ParseError> {
+    let answers = parse_lines(text, |line| match line.trim_start() {
+        "" => Ok(None),
+        comment if comment.starts_with('#') => Ok(None),
+        _ => Answer::parse(line).map(Some),
+    })?;
+    Ok(answers.into_iter().flatten().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answer, parse};
+    use std::path::PathBuf;
+
+    #[test]
+    fn parses_answers() {
+        let answers = parse("# day part input answer\n\n1 2 day-one/expenses.dat 143933922\n").unwrap();
+        assert_eq!(answers, vec![Answer { day: 1, part: 2, input: PathBuf::from("day-one/expenses.dat"), answer: "143933922".to_string() }]);
+        let error = parse("1 1 day-one/expenses.dat 898299\n1 3 day-one/expenses.dat 1").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 3, "part 1 or 2"));
+        assert!(parse("1 1 day-one/expenses.dat").is_err());
+    }
+}
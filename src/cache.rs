@@ -0,0 +1,156 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+/// Where answers are cached, unless `AOC_CACHE_DIR` says otherwise. `cargo clean` clears it too.
+pub const CACHE_DIR: &str = "target/aoc-cache";
+const FILE: &str = "answers.txt";
+
+/// Cached answer for one part of a day, valid for the input with the `input` digest and the solver at `version`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub version: String,
+    pub answer: String,
+    /// Where the input was read from when the answer was cached, only for listing
+    pub path: String,
+}
+
+impl Entry {
+    /// Parses a `<day> <part> <input digest> <version> <answer> <path>` line, answer and path [`escape`]d
+    fn parse(line: &str) -> Option<Entry> {
+        let mut fields = line.splitn(6, ' ');
+        Some(Entry {
+            day: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            input: fields.next()?.to_string(),
+            version: fields.next()?.to_string(),
+            answer: unescape(fields.next()?)?,
+            path: unescape(fields.next()?)?,
+        })
+    }
+    /// The entry as a line of the cache file
+    fn line(&self) -> String {
+        format!("{} {} {} {} {} {}\n", self.day, self.part, self.input, self.version, escape(&self.answer), escape(&self.path))
+    }
+}
+
+/// `text` as a single field of a line: backslashes, spaces and line breaks become `\\`, `\s`, `\n` and `\r`
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace(' ', "\\s").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+fn unescape(field: &str) -> Option<String> {
+    let mut text = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        text.push(match c {
+            '\\' => match chars.next()? {
+                '\\' => '\\',
+                's' => ' ',
+                'n' => '\n',
+                'r' => '\r',
+                _ => return None,
+            },
+            c => c,
+        });
+    }
+    Some(text)
+}
+
+/// SHA-256 of the input text, as hex
+pub fn digest(input: &str) -> String {
+    Sha256::digest(input.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Answers solved before, stored as one line per answer
+pub struct Cache {
+    file: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl Cache {
+    /// Cache in `AOC_CACHE_DIR` or [`CACHE_DIR`]
+    pub fn open_default() -> io::Result<Cache> {
+        Cache::open(env::var_os("AOC_CACHE_DIR").map_or_else(|| PathBuf::from(CACHE_DIR), PathBuf::from))
+    }
+    /// Cache kept in `dir`, empty when there's nothing there yet. Lines that don't parse are dropped.
+    pub fn open<P: AsRef<Path>>(dir: P) -> io::Result<Cache> {
+        let file = dir.as_ref().join(FILE);
+        let text = match fs::read_to_string(&file) {
+            Ok(text) => text,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(error),
+        };
+        Ok(Cache { file, entries: text.lines().filter_map(Entry::parse).collect() })
+    }
+    pub fn get(&self, day: u8, part: u8, input: &str, version: &str) -> Option<&str> {
+        self.entries.iter()
+            .find(|entry| entry.day == day && entry.part == part && entry.input == input && entry.version == version)
+            .map(|entry| entry.answer.as_str())
+    }
+    /// Adds `entry`, replacing the answer for the same day, part, input and version
+    pub fn insert(&mut self, entry: Entry) {
+        self.entries.retain(|cached| (cached.day, cached.part, &cached.input, &cached.version) != (entry.day, entry.part, &entry.input, &entry.version));
+        self.entries.push(entry);
+    }
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+    /// Drops the entries of `day`, or all of them. Returns how many went.
+    pub fn clear(&mut self, day: Option<u8>) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|entry| day.is_some_and(|day| day != entry.day));
+        before - self.entries.len()
+    }
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(dir) = self.file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = self.entries.iter().map(Entry::line).collect::<String>();
+        fs::write(&self.file, text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cache, Entry, digest};
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn round_trip() {
+        let dir = env::temp_dir().join(format!("aoc-cache-test-{}", std::process::id()));
+        let input = digest("1721\n979");
+        assert_eq!(input.len(), 64);
+        let mut cache = Cache::open(&dir).unwrap();
+        assert!(cache.entries().is_empty());
+        let entry = Entry { day: 1, part: 1, input: input.clone(), version: "0.1.0+1".to_string(), answer: "514579".to_string(), path: "my input.txt".to_string() };
+        cache.insert(entry.clone());
+        cache.insert(Entry { day: 2, ..entry.clone() });
+        cache.insert(Entry { answer: "1".to_string(), ..entry.clone() });
+        cache.save().unwrap();
+
+        let mut cache = Cache::open(&dir).unwrap();
+        assert_eq!(cache.get(1, 1, &input, "0.1.0+1"), Some("1"));
+        assert_eq!(cache.get(1, 1, &input, "0.1.0+2"), None);
+        assert_eq!(cache.get(1, 1, &digest("1721\n978"), "0.1.0+1"), None);
+        assert_eq!(cache.entries()[1].path, "my input.txt");
+        // Answers of day 21 and of the day binaries can hold spaces, nothing may break the line
+        let spaced = Entry { day: 21, part: 2, answer: "mxmxvkd sqjhc\nfvjkl \\s".to_string(), path: "a\\b c".to_string(), ..entry.clone() };
+        cache.insert(spaced.clone());
+        cache.save().unwrap();
+        let mut cache = Cache::open(&dir).unwrap();
+        assert_eq!(cache.entries().last(), Some(&spaced));
+        assert_eq!(cache.get(21, 2, &input, "0.1.0+1"), Some("mxmxvkd sqjhc\nfvjkl \\s"));
+        assert_eq!(Entry::parse("1 1 digest 0.1.0+1 a\\x path"), None);
+        assert_eq!(cache.clear(Some(2)), 1);
+        assert_eq!(cache.clear(None), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
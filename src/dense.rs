@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use std::mem;
+use std::ops::{Index, IndexMut};
+
+/// Map from integer keys to values that keeps keys below `limit` in a vector indexed by the key, and any larger
+/// key in a `HashMap`. Keys never set read as the default value. Days 15 and 23 look up millions of small
+/// numbers: indexing skips the hashing, and the limit keeps a few huge keys from allocating a huge vector.
+#[derive(Debug, Clone, Default)]
+pub struct DenseMap<V> {
+    limit: usize,
+    values: Vec<V>,
+    sparse: HashMap<usize, V>,
+}
+
+impl<V: Copy + Default> DenseMap<V> {
+    /// Empty map, the vector grows as keys below `limit` are set
+    pub fn new(limit: usize) -> DenseMap<V> {
+        DenseMap { limit, values: Vec::new(), sparse: HashMap::new() }
+    }
+    /// Map with every key below `limit` already in the vector
+    pub fn filled(limit: usize) -> DenseMap<V> {
+        DenseMap { limit, values: vec![V::default(); limit], sparse: HashMap::new() }
+    }
+    pub fn get(&self, key: usize) -> V {
+        match key < self.limit {
+            true => self.values.get(key).copied().unwrap_or_default(),
+            false => self.sparse.get(&key).copied().unwrap_or_default(),
+        }
+    }
+    /// Sets `key` to `value`, returns what it was before
+    pub fn insert(&mut self, key: usize, value: V) -> V {
+        if key >= self.limit {
+            return self.sparse.insert(key, value).unwrap_or_default();
+        }
+        if key >= self.values.len() {
+            // Double at least, like `Vec::push` does
+            self.values.resize((key + 1).max(2 * self.values.len()).min(self.limit), V::default());
+        }
+        mem::replace(&mut self.values[key], value)
+    }
+}
+
+/// Plain vector access for keys already in the vector, like every key of a `filled` map, panics for any other key
+impl<V> Index<usize> for DenseMap<V> {
+    type Output = V;
+
+    fn index(&self, key: usize) -> &V {
+        &self.values[key]
+    }
+}
+
+impl<V> IndexMut<usize> for DenseMap<V> {
+    fn index_mut(&mut self, key: usize) -> &mut V {
+        &mut self.values[key]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DenseMap;
+
+    #[test]
+    fn dense_and_sparse_keys() {
+        let mut map = DenseMap::<u32>::new(100);
+        assert_eq!(map.get(5), 0);
+        assert_eq!(map.insert(5, 7), 0);
+        assert_eq!(map.insert(5, 8), 7);
+        assert_eq!(map.insert(1 << 40, 9), 0);
+        assert_eq!((map.get(5), map.get(1 << 40), map.get(99)), (8, 9, 0));
+        assert_eq!((map.values.len(), map.sparse.len()), (6, 1));
+        map.insert(60, 1);
+        assert_eq!(map.values.len(), 61);
+        map.insert(70, 1);
+        assert_eq!(map.values.len(), 100);
+        assert_eq!(map[5], 8);
+        let mut filled = DenseMap::<u8>::filled(10);
+        filled[9] = 3;
+        assert_eq!((filled.values.len(), filled.get(9)), (10, 3));
+    }
+}
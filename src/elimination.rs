@@ -0,0 +1,26 @@
+/// Pairs slots with candidates when each candidate belongs to one slot: a slot left with a single candidate
+/// gets it, and that candidate is struck from every other slot, until no slot is down to one.
+/// Returns the candidate of each slot, `None` where several remain or none ever fit.
+pub fn eliminate<T: Clone + PartialEq>(mut candidates: Vec<Vec<T>>) -> Vec<Option<T>> {
+    let mut settled = vec![None; candidates.len()];
+    while let Some(slot) = candidates.iter().position(|candidates| candidates.len() == 1) {
+        let candidate = candidates[slot][0].clone();
+        for candidates in candidates.iter_mut() {
+            candidates.retain(|other| *other != candidate);
+        }
+        settled[slot] = Some(candidate);
+    }
+    settled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::eliminate;
+
+    #[test]
+    fn settles_one_at_a_time() {
+        assert_eq!(eliminate(vec![vec![0, 1], vec![0], vec![0, 1, 2]]), vec![Some(1), Some(0), Some(2)]);
+        assert_eq!(eliminate(vec![vec!["a", "b"], vec!["a", "b"], vec!["c"]]), vec![None, None, Some("c")]);
+        assert_eq!(eliminate::<u8>(vec![vec![]]), vec![None]);
+    }
+}
@@ -0,0 +1,150 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Malformed puzzle input, pointing at the offending text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the input
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Error about `fragment`, a slice of `source`. Line and column are where the slice starts in `source`,
+    /// a fragment from elsewhere is reported at the start of `source`.
+    pub fn new(source: &str, fragment: &str, expected: impl Into<String>) -> ParseError {
+        let start = source.as_ptr() as usize;
+        let offset = (fragment.as_ptr() as usize).checked_sub(start)
+            .filter(|offset| offset + fragment.len() <= source.len())
+            .unwrap_or(0);
+        let before = source.get(..offset).unwrap_or("");
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: fragment.to_string(),
+            expected: expected.into(),
+        }
+    }
+    /// Moves the error down by `lines`, for parsers that only saw a part of the input
+    pub fn offset_lines(mut self, lines: usize) -> ParseError {
+        self.line += lines;
+        self
+    }
+    /// Moves an error found while parsing `fragment` on its own to where `fragment` sits in `source`
+    pub fn within(self, source: &str, fragment: &str) -> ParseError {
+        let start = ParseError::new(source, fragment, "");
+        ParseError {
+            column: if self.line == 1 { self.column + start.column - 1 } else { self.column },
+            line: self.line + start.line - 1,
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}, ", self.line, self.column, self.expected)?;
+        match self.text.as_str() {
+            "" => write!(f, "found nothing"),
+            text => write!(f, "found {:?}", text),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Well formed puzzle input that a part has no answer for, saying what the input lacks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoAnswer {
+    pub expected: String,
+}
+
+impl NoAnswer {
+    pub fn new(expected: impl Into<String>) -> NoAnswer {
+        NoAnswer { expected: expected.into() }
+    }
+}
+
+impl fmt::Display for NoAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no answer, expected {}", self.expected)
+    }
+}
+
+impl Error for NoAnswer {}
+
+/// Why a part of a day wasn't solved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Malformed(ParseError),
+    NoAnswer(NoAnswer),
+}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> SolveError {
+        SolveError::Malformed(error)
+    }
+}
+
+impl From<NoAnswer> for SolveError {
+    fn from(error: NoAnswer) -> SolveError {
+        SolveError::NoAnswer(error)
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Malformed(error) => write!(f, "malformed input: {}", error),
+            SolveError::NoAnswer(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// Parses every line of `input` on its own, errors are numbered by the line they come from
+pub fn parse_lines<T, F>(input: &str, parse: F) -> Result<Vec<T>, ParseError>
+    where F: Fn(&str) -> Result<T, ParseError> {
+    input.lines().enumerate()
+        .map(|(index, line)| parse(line).map_err(|error| error.offset_lines(index)))
+        .collect()
+}
+
+/// Parses `token`, a slice of `source`, as a number
+pub fn number<T: FromStr>(source: &str, token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::new(source, token, "a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseError, parse_lines, number};
+
+    #[test]
+    fn locates_fragment() {
+        let input = "1\n22\n3x3";
+        let error = ParseError::new(input, &input[6..7], "a digit");
+        assert_eq!((error.line, error.column, error.text.as_str()), (3, 2, "x"));
+        assert_eq!(error.to_string(), r#"line 3, column 2: expected a digit, found "x""#);
+    }
+
+    #[test]
+    fn moves_into_source() {
+        let input = "a: 1\nb: 2x";
+        let value = &input[8..];
+        let error = number::<u8>(value, value).unwrap_err().within(input, value);
+        assert_eq!((error.line, error.column), (2, 4));
+    }
+
+    #[test]
+    fn numbers_lines() {
+        assert_eq!(parse_lines("1\n2\n3", |line| number::<u8>(line, line)), Ok(vec![1, 2, 3]));
+        let error = parse_lines("1\n2\n 3a", |line| number::<u8>(line, line.trim())).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (3, 2, "3a"));
+    }
+}
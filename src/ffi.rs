@@ -0,0 +1,118 @@
+//! C ABI of the solvers, declared in `include/aoc.h`. Built into the `cdylib` next to the Rust library.
+
+use std::ffi::{c_char, CString};
+use std::panic;
+use std::ptr;
+use std::slice;
+use std::str;
+
+use crate::error::SolveError;
+use crate::runner;
+use crate::solution::puzzle;
+
+/// Bumped whenever a function or status in `include/aoc.h` changes meaning
+pub const ABI_VERSION: u32 = 1;
+
+/// Result of [`aoc_solve`], the `aoc_status` enum of the header
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok = 0,
+    NoSuchDay = 1,
+    NoSuchPart = 2,
+    NotUtf8 = 3,
+    MalformedInput = 4,
+    Panicked = 5,
+    NullArgument = 6,
+}
+
+#[no_mangle]
+pub extern "C" fn aoc_abi_version() -> u32 {
+    ABI_VERSION
+}
+
+/// Solves `part` of `day` for the `length` bytes of UTF-8 input at `input`, which need no terminating NUL.
+/// Unless `answer` is null, it is set to a new string holding the answer on `Ok`, or what went wrong otherwise,
+/// to be released with [`aoc_string_free`].
+///
+/// # Safety
+/// `input` points to `length` readable bytes, `answer` is null or points to a writable `char *`.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(day: u8, part: u8, input: *const u8, length: usize, answer: *mut *mut c_char) -> Status {
+    let (status, text) = solve(day, part, input, length);
+    if !answer.is_null() {
+        // An answer never holds a NUL, an error message only when quoting the input
+        *answer = CString::new(text.replace('\0', "\\0")).map_or(ptr::null_mut(), CString::into_raw);
+    }
+    status
+}
+
+unsafe fn solve(day: u8, part: u8, input: *const u8, length: usize) -> (Status, String) {
+    if input.is_null() && length > 0 {
+        return (Status::NullArgument, "input is null".to_string());
+    }
+    let puzzle = match puzzle(day) {
+        Some(puzzle) => puzzle,
+        None => return (Status::NoSuchDay, format!("no solution for day {}", day)),
+    };
+    if part != 1 && part != 2 {
+        return (Status::NoSuchPart, format!("no part {}, only 1 or 2", part));
+    }
+    let bytes = match length {
+        0 => &[][..],
+        _ => slice::from_raw_parts(input, length),
+    };
+    let text = match str::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(error) => return (Status::NotUtf8, format!("input is not UTF-8: {}", error)),
+    };
+    // Input a solver has no answer for is turned away by its `parse`. A panic is a bug, it is only caught so it
+    // never unwinds into C, and an abort such as running out of memory can't be caught at all.
+    match panic::catch_unwind(|| puzzle.solve(text, part)) {
+        Ok(Ok(solved)) => (Status::Ok, solved.answer),
+        Ok(Err(SolveError::Malformed(error))) => (Status::MalformedInput, error.to_string()),
+        Ok(Err(SolveError::NoAnswer(error))) => (Status::MalformedInput, error.to_string()),
+        Err(payload) => (Status::Panicked, runner::message(payload)),
+    }
+}
+
+/// Releases a string from [`aoc_solve`], null is ignored
+///
+/// # Safety
+/// `string` is null or came from [`aoc_solve`] and wasn't freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{aoc_solve, aoc_string_free, Status};
+    use std::ffi::CStr;
+    use std::ptr;
+
+    fn solve(day: u8, part: u8, input: &[u8]) -> (Status, String) {
+        let mut answer = ptr::null_mut();
+        unsafe {
+            let status = aoc_solve(day, part, input.as_ptr(), input.len(), &mut answer);
+            let text = CStr::from_ptr(answer).to_str().unwrap().to_string();
+            aoc_string_free(answer);
+            (status, text)
+        }
+    }
+
+    #[test]
+    fn solves_through_the_c_abi() {
+        assert_eq!(solve(1, 1, b"1721\n979\n366\n299\n675\n1456"), (Status::Ok, "514579".to_string()));
+        assert_eq!(solve(18, 2, b"2 * 3 + (4 * 5)").1, "46");
+        assert_eq!(solve(1, 1, b"1721\n\0x"), (Status::MalformedInput, "line 2, column 1: expected a number, found \"\\0x\"".to_string()));
+        assert_eq!(solve(5, 1, b""), (Status::MalformedInput, "no answer, expected at least one boarding pass".to_string()));
+        assert_eq!(solve(14, 2, format!("mask = {}\nmem[8] = 11", "X".repeat(36)).as_bytes()).0, Status::MalformedInput);
+        assert_eq!(solve(1, 1, b"\xff").0, Status::NotUtf8);
+        assert_eq!(solve(0, 1, b"").0, Status::NoSuchDay);
+        assert_eq!(solve(1, 3, b"").0, Status::NoSuchPart);
+        assert_eq!(unsafe { aoc_solve(1, 1, ptr::null(), 4, ptr::null_mut()) }, Status::NullArgument);
+    }
+}
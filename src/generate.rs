@@ -0,0 +1,51 @@
+use rand::Rng as _;
+use rand::SeedableRng;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+/// Random source for the input generators. ChaCha keeps a seed producing the same input on every platform and release.
+pub type Rng = ChaCha8Rng;
+
+pub fn rng(seed: u64) -> Rng {
+    Rng::seed_from_u64(seed)
+}
+
+/// `count` different values from `population`, in random order
+pub fn distinct<T: Clone>(rng: &mut Rng, population: &[T], count: usize) -> Vec<T> {
+    population.choose_multiple(rng, count).cloned().collect()
+}
+
+/// Random string of `length` characters from `alphabet`
+pub fn word(rng: &mut Rng, alphabet: &[u8], length: usize) -> String {
+    (0..length).map(|_| *alphabet.choose(rng).unwrap() as char).collect()
+}
+
+/// Map of `width` by `height` characters, each one `filled` with the given probability and `empty` otherwise
+pub fn character_map(rng: &mut Rng, width: usize, height: usize, filled: (char, f64), empty: char) -> String {
+    let (full, probability) = filled;
+    (0..height)
+        .map(|_| (0..width).map(|_| if rng.gen_bool(probability) { full } else { empty }).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Primes in `range`, used where the puzzles need pairwise coprime numbers
+pub fn primes(range: std::ops::Range<u64>) -> Vec<u64> {
+    range.filter(|&n| n > 1 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rng, distinct, primes, character_map};
+
+    #[test]
+    fn seeded() {
+        let first = character_map(&mut rng(7), 5, 3, ('#', 0.5), '.');
+        assert_eq!(first, character_map(&mut rng(7), 5, 3, ('#', 0.5), '.'));
+        assert_eq!(first.lines().count(), 3);
+        let mut picked = distinct(&mut rng(1), &[1, 2, 3, 4], 4);
+        picked.sort_unstable();
+        assert_eq!(picked, vec![1, 2, 3, 4]);
+        assert_eq!(primes(0..20), vec![2, 3, 5, 7, 11, 13, 17, 19]);
+    }
+}
@@ -0,0 +1,289 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+/// `(x, y)` of a square, `x` counts columns from the left and `y` rows from the top
+pub type Point = (usize, usize);
+/// Step between two squares, as `(dx, dy)`
+pub type Direction = (isize, isize);
+
+/// Up, right, down and left
+pub const ORTHOGONAL: [Direction; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+/// Orthogonal and diagonal steps, row by row from the top left
+pub const ALL_AROUND: [Direction; 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// Square of a map that's drawn as a single character
+pub trait Tile: Sized {
+    /// What the parser reports when it finds some other character, like "`#` or `.`"
+    const EXPECTED: &'static str;
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+/// Rectangular map, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    squares: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, all of them `width` long
+    pub fn from_rows(width: usize, rows: Vec<Vec<T>>) -> Grid<T> {
+        assert!(rows.iter().all(|row| row.len() == width), "every row has to be {} squares long", width);
+        Grid {
+            width,
+            height: rows.len(),
+            squares: rows.into_iter().flatten().collect(),
+        }
+    }
+    /// Parses a rectangular map with one square per character, `square` rejects characters that don't belong on the map
+    pub fn parse<F>(input: &str, expected: &str, square: F) -> Result<Grid<T>, ParseError>
+        where F: Fn(char) -> Option<T> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (index, line) in input.lines().enumerate() {
+            let row = line.char_indices()
+                .map(|(at, c)| square(c).ok_or_else(|| ParseError::new(line, &line[at..at + c.len_utf8()], expected)))
+                .collect::<Result<Vec<T>, ParseError>>()
+                .map_err(|error| error.offset_lines(index))?;
+            if let Some(first) = rows.first() {
+                if first.len() != row.len() {
+                    return Err(ParseError::new(line, line, format!("a row of {} squares", first.len())).offset_lines(index));
+                }
+            }
+            rows.push(row);
+        }
+        match rows.first().map(Vec::len) {
+            Some(width) if width > 0 => Ok(Grid::from_rows(width, rows)),
+            _ => Err(ParseError::new(input, &input[input.len()..], "at least one row of squares")),
+        }
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    /// Bounds-checked access
+    pub fn get(&self, (x, y): Point) -> Option<&T> {
+        match x < self.width && y < self.height {
+            true => self.squares.get(y * self.width + x),
+            false => None,
+        }
+    }
+    pub fn get_mut(&mut self, (x, y): Point) -> Option<&mut T> {
+        match x < self.width && y < self.height {
+            true => self.squares.get_mut(y * self.width + x),
+            false => None,
+        }
+    }
+    /// Access on a map that repeats itself in every direction
+    pub fn get_wrapping(&self, (x, y): Point) -> &T {
+        &self[(x % self.width, y % self.height)]
+    }
+    /// Square one `direction` step away from `point`, if it's still on the map
+    pub fn step(&self, (x, y): Point, (dx, dy): Direction) -> Option<Point> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+    /// Up to four squares sharing an edge with `point`
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item=Point> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&direction| self.step(point, direction))
+    }
+    /// Up to eight squares sharing an edge or a corner with `point`
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item=Point> + '_ {
+        ALL_AROUND.iter().filter_map(move |&direction| self.step(point, direction))
+    }
+    /// Squares seen from `point` looking towards `direction`, nearest first, until the edge of the map
+    pub fn ray(&self, point: Point, direction: Direction) -> impl Iterator<Item=Point> + '_ {
+        let mut current = point;
+        std::iter::from_fn(move || {
+            current = self.step(current, direction)?;
+            Some(current)
+        })
+    }
+    /// Every point, row by row
+    pub fn points(&self) -> impl Iterator<Item=Point> {
+        let width = self.width;
+        (0..self.squares.len()).map(move |index| (index % width, index / width))
+    }
+    /// Every point with its square, row by row
+    pub fn iter(&self) -> impl Iterator<Item=(Point, &T)> {
+        self.points().zip(self.squares.iter())
+    }
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.squares[y * self.width..(y + 1) * self.width]
+    }
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        self.squares.chunks(self.width)
+    }
+    pub fn column(&self, x: usize) -> impl Iterator<Item=&T> {
+        self.squares[x..].iter().step_by(self.width)
+    }
+    pub fn columns(&self) -> impl Iterator<Item=impl Iterator<Item=&T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+    /// Same map with every square replaced by `f` of it
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            squares: self.squares.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// `width` by `height` map of `square`s
+    pub fn new(width: usize, height: usize, square: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            squares: vec![square; width * height],
+        }
+    }
+    /// `width` by `height` map, each square copied from `source` of its point
+    fn copied<F: Fn(Point) -> Point>(&self, width: usize, height: usize, source: F) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            squares: (0..width * height).map(|index| self[source((index % width, index / width))].clone()).collect(),
+        }
+    }
+    /// Same map turned a quarter clockwise, the left column becomes the top row
+    pub fn rotate(&self) -> Grid<T> {
+        let height = self.height;
+        self.copied(height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+    /// Same map mirrored left to right
+    pub fn flip(&self) -> Grid<T> {
+        let width = self.width;
+        self.copied(width, self.height, |(x, y)| (width - 1 - x, y))
+    }
+    /// The map in each of its 8 orientations: turned 0 to 3 quarters, then the same flipped
+    pub fn orientations(&self) -> Vec<Grid<T>> {
+        let mut orientations = Vec::with_capacity(8);
+        for mut grid in [self.clone(), self.flip()] {
+            for _ in 0..4 {
+                let turned = grid.rotate();
+                orientations.push(grid);
+                grid = turned;
+            }
+        }
+        orientations
+    }
+    /// The `width` by `height` part of the map with `(x, y)` as its top left square
+    pub fn crop(&self, (x, y): Point, width: usize, height: usize) -> Grid<T> {
+        assert!(x + width <= self.width && y + height <= self.height, "{}x{} at {:?} is outside of a {}x{} grid",
+                width, height, (x, y), self.width, self.height);
+        self.copied(width, height, |(dx, dy)| (x + dx, y + dy))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", point, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point).unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", point, width, height))
+    }
+}
+
+impl<T: Tile> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, T::EXPECTED, T::from_char)
+    }
+}
+
+impl<T: Tile> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            row.iter().try_for_each(|square| write!(f, "{}", square.to_char()))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grid, Tile, ORTHOGONAL};
+    use std::str::FromStr;
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    struct Bit(bool);
+
+    impl Tile for Bit {
+        const EXPECTED: &'static str = "`0` or `1`";
+        fn from_char(c: char) -> Option<Bit> {
+            match c {
+                '0' => Some(Bit(false)),
+                '1' => Some(Bit(true)),
+                _ => None
+            }
+        }
+        fn to_char(&self) -> char {
+            if self.0 { '1' } else { '0' }
+        }
+    }
+
+    #[test]
+    fn rectangular_grid() {
+        let bit = |c: char| c.to_digit(2);
+        assert_eq!(Grid::parse("01\n10", "0 or 1", bit), Ok(Grid::from_rows(2, vec![vec![0, 1], vec![1, 0]])));
+        assert_eq!(Grid::parse("01\n12", "0 or 1", bit).unwrap_err().column, 2);
+        assert_eq!(Grid::parse("01\n1", "0 or 1", bit).unwrap_err().expected, "a row of 2 squares");
+        assert!(Grid::parse("", "0 or 1", bit).is_err());
+    }
+
+    #[test]
+    fn access_and_rendering() {
+        let input = "011\n100";
+        let grid = Grid::<Bit>::from_str(input).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((2, 0)), Some(&Bit(true)));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get_wrapping((3, 3)), &Bit(true));
+        assert_eq!(grid.row(1), &[Bit(true), Bit(false), Bit(false)]);
+        assert_eq!(grid.column(1).collect::<Vec<_>>(), vec![&Bit(true), &Bit(false)]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.iter().filter(|(_, bit)| bit.0).map(|(point, _)| point).collect::<Vec<_>>(), vec![(1, 0), (2, 0), (0, 1)]);
+        assert_eq!(grid.to_string(), input);
+    }
+
+    #[test]
+    fn orientations() {
+        let grid = Grid::parse("123\n456", "a digit", |c| c.to_digit(10)).unwrap();
+        assert_eq!(grid.rotate(), Grid::from_rows(2, vec![vec![4, 1], vec![5, 2], vec![6, 3]]));
+        assert_eq!(grid.flip(), Grid::from_rows(3, vec![vec![3, 2, 1], vec![6, 5, 4]]));
+        assert_eq!(grid.rotate().rotate().rotate().rotate(), grid);
+        let orientations = grid.orientations();
+        assert_eq!(orientations.len(), 8);
+        assert!(orientations.iter().enumerate().all(|(index, one)| orientations[..index].iter().all(|other| other != one)));
+        assert_eq!(grid.crop((1, 0), 2, 2), Grid::from_rows(2, vec![vec![2, 3], vec![5, 6]]));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(4, 3, 0);
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((3, 2)).count(), 3);
+        assert_eq!(grid.ray((1, 1), (1, 0)).collect::<Vec<_>>(), vec![(2, 1), (3, 1)]);
+        assert_eq!(ORTHOGONAL.iter().map(|&direction| grid.ray((0, 0), direction).count()).sum::<usize>(), 5);
+    }
+}
@@ -0,0 +1,37 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+/// Reads a whole puzzle input from any buffered reader
+pub fn read<R: BufRead>(mut reader: R) -> io::Result<String> {
+    let mut buffer = String::new();
+    reader.read_to_string(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// Opens a puzzle input file, `-` stands for stdin
+pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Box<dyn BufRead>> {
+    match path.as_ref() {
+        path if path == Path::new("-") => Ok(Box::new(io::stdin().lock())),
+        path => Ok(Box::new(BufReader::new(File::open(path)?))),
+    }
+}
+
+/// Input for a day's binary: the file given as the first argument, or the checked-in `default` when there's none
+pub fn from_args(default: &str) -> io::Result<String> {
+    match env::args_os().nth(1) {
+        Some(path) => read(open(path)?),
+        None => read(open(default)?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read;
+
+    #[test]
+    fn reads_any_buf_read() {
+        assert_eq!(read("1\n2\n".as_bytes()).unwrap(), "1\n2\n");
+    }
+}
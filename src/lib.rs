@@ -0,0 +1,64 @@
+pub mod animate;
+pub mod answers;
+pub mod cache;
+pub mod dense;
+pub mod elimination;
+pub mod error;
+pub mod ffi;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod logging;
+pub mod repl;
+pub mod runner;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod solution;
+pub mod watch;
+
+#[path = "../day-one/src/mod.rs"]
+pub mod day1;
+#[path = "../day_two/src/mod.rs"]
+pub mod day2;
+#[path = "../day_three/src/mod.rs"]
+pub mod day3;
+#[path = "../day_four/src/mod.rs"]
+pub mod day4;
+#[path = "../day-five/src/mod.rs"]
+pub mod day5;
+#[path = "../day-six/src/mod.rs"]
+pub mod day6;
+#[path = "../day-seven/src/mod.rs"]
+pub mod day7;
+#[path = "../day-eight/src/mod.rs"]
+pub mod day8;
+#[path = "../day-nine/src/mod.rs"]
+pub mod day9;
+#[path = "../day-ten/src/mod.rs"]
+pub mod day10;
+#[path = "../day-eleven/src/mod.rs"]
+pub mod day11;
+#[path = "../day-twelve/src/mod.rs"]
+pub mod day12;
+#[path = "../day-thirteen/src/mod.rs"]
+pub mod day13;
+#[path = "../day-fourteen/src/mod.rs"]
+pub mod day14;
+#[path = "../day-fifteen/src/mod.rs"]
+pub mod day15;
+#[path = "../day-sixteen/src/mod.rs"]
+pub mod day16;
+#[path = "../day-seventeen/src/mod.rs"]
+pub mod day17;
+#[path = "../day-eighteen/src/mod.rs"]
+pub mod day18;
+#[path = "../day-nineteen/src/mod.rs"]
+pub mod day19;
+#[path = "../day-twenty/src/mod.rs"]
+pub mod day20;
+#[path = "../day-twenty-one/src/mod.rs"]
+pub mod day21;
+#[path = "../day-twenty-two/src/mod.rs"]
+pub mod day22;
+#[path = "../day-twenty-three/src/mod.rs"]
+pub mod day23;
@@ -0,0 +1,43 @@
+use std::io::{self, IsTerminal};
+
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::EnvFilter;
+
+/// Level picked by `-q` or the number of `-v` flags, warnings when there's neither
+pub fn level(quiet: bool, verbose: u8) -> LevelFilter {
+    match (quiet, verbose) {
+        (true, _) => LevelFilter::ERROR,
+        (false, 0) => LevelFilter::WARN,
+        (false, 1) => LevelFilter::INFO,
+        (false, 2) => LevelFilter::DEBUG,
+        (false, _) => LevelFilter::TRACE,
+    }
+}
+
+/// Logs to stderr at `level`. Every day logs under its own module, so `RUST_LOG=aoc_rust::day8=trace`
+/// traces a single day, directives in `RUST_LOG` take precedence over `level`.
+pub fn init(level: LevelFilter) {
+    let filter = EnvFilter::builder()
+        .with_default_directive(level.into())
+        .from_env_lossy();
+    // A second call, e.g. from a test, keeps the first subscriber
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .try_init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::level;
+    use tracing::level_filters::LevelFilter;
+
+    #[test]
+    fn verbosity_flags() {
+        assert_eq!(level(false, 0), LevelFilter::WARN);
+        assert_eq!(level(false, 2), LevelFilter::DEBUG);
+        assert_eq!(level(false, 7), LevelFilter::TRACE);
+        assert_eq!(level(true, 3), LevelFilter::ERROR);
+    }
+}
@@ -1,62 +1,569 @@
-fn main() {
-    let data = Data { ..Default::default() };
-    print!("One: {:#?}", data.part_one());
-    print!("Two: {:#?}", data.part_two());
+use std::fs;
+use std::io;
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+use clap::{Parser, Subcommand, ValueEnum};
+use rustyline::DefaultEditor;
+use rustyline::error::ReadlineError;
+use serde_json::json;
+
+use aoc_rust::{animate, answers, cache, input, logging, repl, runner, watch};
+use aoc_rust::repl::Repl;
+use aoc_rust::cache::Cache;
+use aoc_rust::runner::{Job, Outcome, Report};
+use aoc_rust::solution::{CALENDAR, Puzzle, Solved, puzzle};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2020 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log more, repeat for debug (`-vv`) and trace (`-vvv`) output. `RUST_LOG` picks single days
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    /// Log errors only
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
 }
 
-// #[derive(Default)]
-pub struct Data {
-    entries: Vec<u32>
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day or the whole calendar
+    Run {
+        /// Day to solve (1-23)
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=LAST_DAY as i64))]
+        day: Option<u8>,
+        /// Solve every day
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+        /// Solve only one part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, `-` reads stdin. Defaults to the day's checked-in puzzle input
+        #[arg(long, conflicts_with = "all")]
+        input: Option<PathBuf>,
+        /// Solve every file in this directory and print a table of their answers, checked against the known ones
+        #[arg(long, conflicts_with_all = ["all", "input"], requires = "day")]
+        inputs_dir: Option<PathBuf>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Days solved at the same time, each on its own thread
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: u16,
+        /// Solve everything again instead of reusing answers cached for the same input and solver
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// Check the solutions against the known answers
+    Verify {
+        /// Check only this day
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=LAST_DAY as i64))]
+        day: Option<u8>,
+        /// Answers file, one `<day> <part> <input> <answer>` per line
+        #[arg(long, default_value = answers::ANSWERS)]
+        answers: PathBuf,
+    },
+    /// Print a random, well formed input for a day
+    Gen {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=LAST_DAY as i64))]
+        day: u8,
+        /// About how many records to generate: lines, passports, rules or the side of a map
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// Same seed, same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Solve a day again whenever its input or one of its example inputs changes
+    Watch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=LAST_DAY as i64))]
+        day: u8,
+        /// Solve only one part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Files to watch, can be repeated. Defaults to the day's puzzle input and the inputs it has known answers for
+        #[arg(long)]
+        input: Vec<PathBuf>,
+    },
+    /// Play every generation of day 11 or 17 in the terminal, or save them as images
+    Animate {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=LAST_DAY as i64))]
+        day: u8,
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Input file, `-` reads stdin. Defaults to the day's checked-in puzzle input
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Milliseconds between generations
+        #[arg(long, default_value_t = 200)]
+        delay: u64,
+        /// Save the frames into this directory instead of playing them
+        #[arg(long)]
+        export: Option<PathBuf>,
+        /// Image format of the saved frames
+        #[arg(long, value_enum, default_value_t = Export::Ppm, requires = "export")]
+        image: Export,
+        /// Pixels per square of the saved frames
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..), requires = "export")]
+        scale: u16,
+    },
+    /// Type the language of day 8, 14 or 18 a line at a time and look at its state
+    Repl {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=LAST_DAY as i64))]
+        day: u8,
+    },
+    /// Answer `POST /day/{n}/part/{p}` requests, the body being the puzzle input
+    #[cfg(feature = "server")]
+    Serve {
+        /// Port on localhost
+        #[arg(long, default_value_t = 2020)]
+        port: u16,
+        /// Requests answered at the same time
+        #[arg(short, long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: u16,
+    },
+    /// Look at or clear the answers cached by `run`
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
 }
 
-impl Default for Data {
-    fn default() -> Data {
-        Data {
-            entries: vec![1810, 1729, 1857, 1777, 1927, 1936, 1797, 1719, 1703, 1758, 1768, 2008, 1963, 1925, 1919, 1911, 1782, 2001, 1744, 1738, 1742, 1799, 1765, 1819, 1888, 127, 1880, 1984, 1697, 1760, 1680, 1951, 1745, 1817, 1704, 1736, 1969, 1705, 1690, 1848, 1885, 1912, 1982, 1895, 1959, 1769, 1722, 1807, 1901, 1983, 1993, 1871, 1795, 1955, 1921, 1934, 1743, 1899, 1942, 1964, 1034, 1952, 1851, 1716, 1800, 1771, 1945, 1877, 1917, 1930, 1970, 1948, 1914, 1767, 1910, 563, 1121, 1897, 1946, 1882, 1739, 1900, 1714, 1931, 2000, 311, 1881, 1876, 354, 1965, 1842, 1979, 1998, 1960, 1852, 1847, 1938, 1369, 1780, 1698, 1753, 1746, 1868, 1752, 1802, 1892, 1755, 1818, 1913, 1706, 1862, 326, 1941, 1926, 1809, 1879, 1815, 1939, 1859, 1999, 1947, 1898, 1794, 1737, 1971, 1977, 1944, 1812, 1905, 1359, 1788, 1754, 1774, 1825, 1748, 1701, 1791, 1786, 1692, 1894, 1961, 1902, 1849, 1967, 1770, 1987, 1831, 1728, 1896, 1805, 1733, 1918, 1731, 661, 1776, 1494, 2005, 2009, 2004, 1915, 1695, 1710, 1804, 1929, 1725, 1772, 1933, 609, 1708, 1822, 1978, 1811, 1816, 1073, 1874, 1845, 1989, 1696, 1953, 1823, 1923, 1907, 1834, 1806, 1861, 1785, 297, 1968, 1764, 1932, 1937, 1826, 1732, 1962, 1916, 1756, 1975, 1775, 1922, 1773]
-        }
-    }
+#[derive(Subcommand)]
+enum CacheAction {
+    /// List every cached answer
+    List,
+    /// Drop cached answers, of a single day or all of them
+    Clear {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=LAST_DAY as i64))]
+        day: Option<u8>,
+    },
 }
 
-impl Data {
-    pub fn part_one(&self) -> Vec<u32> {
-        let mut ans: Vec<u32> = Vec::new();
-        for val in 0..self.entries.len() {
-            for iter in val..self.entries.len() {
-                if self.entries[val] + self.entries[iter] == 2020 {
-                    ans.push(self.entries[val] * self.entries[iter])
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum Export {
+    /// A color `frame-NNNN.ppm` per generation
+    Ppm,
+    /// A grayscale `frame-NNNN.pgm` per generation
+    Pgm,
+    /// A single looping `animation.gif`
+    #[cfg(feature = "gif")]
+    Gif,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// `Day N part P: answer` lines
+    Text,
+    /// One `{day, part, answer, parse_ns, solve_ns, input_path, cached}` object per line, answers are strings
+    Json,
+}
+
+const LAST_DAY: u8 = CALENDAR.len() as u8;
+
+fn main() {
+    let cli = Cli::parse();
+    logging::init(logging::level(cli.quiet, cli.verbose));
+    match cli.command {
+        Command::Run { day, all, part, input: path, inputs_dir, format, jobs, no_cache } => {
+            let targets = match (day, &inputs_dir) {
+                (Some(day), Some(dir)) => dir_inputs(dir).unwrap_or_else(|error| {
+                    eprintln!("Can't list {}: {}", dir.display(), error);
+                    process::exit(1);
+                }).into_iter().map(|file| (puzzle(day).unwrap(), file)).collect(),
+                (Some(day), None) if !all => vec![(puzzle(day).unwrap(), path.unwrap_or_else(|| PathBuf::from(puzzle(day).unwrap().input)))],
+                _ => CALENDAR.iter().map(|puzzle| (puzzle, PathBuf::from(puzzle.input))).collect::<Vec<_>>(),
+            };
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            let mut cache = match no_cache {
+                true => None,
+                false => Cache::open_default().map_err(|error| eprintln!("Not using the answer cache: {}", error)).ok(),
+            };
+            let show = |file: &Path, report: &Report| match &report.outcome {
+                Outcome::Solved(solved) => print_answer(format, report, &solved.answer, Some(solved), file),
+                Outcome::Cached(answer) => print_answer(format, report, answer, None, file),
+                Outcome::Malformed(error) => eprintln!("Day {} part {} {}: malformed input: {}", report.day, report.part, file.display(), error),
+                Outcome::NoAnswer(error) => eprintln!("Day {} part {} {}: {}", report.day, report.part, file.display(), error),
+                Outcome::Panicked(message) => eprintln!("Day {} part {} {}: panicked: {}", report.day, report.part, file.display(), message),
+            };
+            let start = Instant::now();
+            // Reports of each target, and the target each job solves
+            let mut reports = targets.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+            let (mut work, mut owners, mut digests) = (Vec::new(), Vec::new(), Vec::new());
+            for (index, &(puzzle, ref file)) in targets.iter().enumerate() {
+                let text = input::open(file).and_then(input::read).unwrap_or_else(|error| {
+                    eprintln!("Can't read {}: {}", file.display(), error);
+                    process::exit(1);
+                });
+                let digest = cache::digest(&text);
+                let mut unsolved = Vec::new();
+                for &part in parts.iter() {
+                    match cache.as_ref().and_then(|cache| cache.get(puzzle.day, part, &digest, &puzzle.version())) {
+                        Some(answer) => reports[index].push(Report { day: puzzle.day, part, time: Duration::ZERO, outcome: Outcome::Cached(answer.to_string()) }),
+                        None => unsolved.push(part),
+                    }
+                }
+                if !unsolved.is_empty() {
+                    work.push(Job { puzzle, input: text, parts: unsolved });
+                    owners.push(index);
                 }
+                digests.push(digest);
             }
-        };
-        ans
-    }
-    pub fn part_two(&self) -> u32 {
-        for val in 0..self.entries.len() {
-            for deep in val..self.entries.len() {
-                for deeper in deep..self.entries.len() {
-                    if self.entries[val] + self.entries[deep] + self.entries[deeper] == 2020 {
-                        return self.entries[val] * self.entries[deep] * self.entries[deeper];
+            for (cached, (_, file)) in reports.iter().zip(targets.iter()) {
+                cached.iter().for_each(|report| show(file, report));
+            }
+            let solved = runner::run(work, jobs as usize, |job, finished| finished.iter().for_each(|report| show(&targets[owners[job]].1, report)));
+            for (job, finished) in solved.into_iter().enumerate() {
+                reports[owners[job]].extend(finished);
+                reports[owners[job]].sort_by_key(|report| report.part);
+            }
+            if let Some(cache) = cache.as_mut() {
+                for ((&(puzzle, ref file), solved), digest) in targets.iter().zip(reports.iter()).zip(digests) {
+                    for report in solved.iter() {
+                        if let Outcome::Solved(solved) = &report.outcome {
+                            cache.insert(cache::Entry {
+                                day: report.day,
+                                part: report.part,
+                                input: digest.clone(),
+                                version: puzzle.version(),
+                                answer: solved.answer.clone(),
+                                path: file.display().to_string(),
+                            });
+                        }
                     }
                 }
+                if let Err(error) = cache.save() {
+                    eprintln!("Can't save the answer cache: {}", error);
+                }
             }
-        };
-        0
+            let mut failed = reports.iter().flatten().any(Report::failed);
+            if inputs_dir.is_some() {
+                let (table, wrong) = inputs_table(&targets, &reports, &known_answers());
+                if format == Format::Text {
+                    println!("\n{}", table);
+                }
+                failed |= wrong > 0;
+            } else if format == Format::Text && targets.len() > 1 {
+                println!("\n{}", runner::summary(&reports.into_iter().flatten().collect::<Vec<_>>(), start.elapsed()));
+            }
+            if failed {
+                process::exit(1);
+            }
+        }
+        Command::Verify { day, answers } => {
+            if !verify(day, &answers) {
+                process::exit(1);
+            }
+        }
+        Command::Gen { day, size, seed } => println!("{}", puzzle(day).unwrap().generate(size, seed)),
+        Command::Watch { day, part, input: files } => {
+            let puzzle = puzzle(day).unwrap();
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            let files = match files.is_empty() {
+                true => day_inputs(puzzle),
+                false => files,
+            };
+            files.iter().for_each(|file| solve_file(puzzle, &parts, file));
+            println!("Watching {}", files.iter().map(|file| file.display().to_string()).collect::<Vec<_>>().join(", "));
+            let watched = watch::watch(&files, |file| {
+                solve_file(puzzle, &parts, file);
+                ControlFlow::Continue(())
+            });
+            if let Err(error) = watched {
+                eprintln!("Can't watch the inputs: {}", error);
+                process::exit(1);
+            }
+        }
+        Command::Animate { day, part, input: path, delay, export, image, scale } => {
+            let puzzle = puzzle(day).unwrap();
+            let file = path.unwrap_or_else(|| PathBuf::from(puzzle.input));
+            let text = input::open(&file).and_then(input::read).unwrap_or_else(|error| {
+                eprintln!("Can't read {}: {}", file.display(), error);
+                process::exit(1);
+            });
+            let frames = match animate::frames(day, part, &text) {
+                Some(Ok(frames)) => frames,
+                Some(Err(error)) => {
+                    eprintln!("Day {} input is malformed: {}", day, error);
+                    process::exit(1);
+                }
+                None => {
+                    eprintln!("Day {} has no generations to animate, try 11 or 17", day);
+                    process::exit(1);
+                }
+            };
+            let delay = Duration::from_millis(delay);
+            let shown = match (export, image) {
+                (None, _) => animate::play(&frames, delay).map(|_| None),
+                #[cfg(feature = "gif")]
+                (Some(dir), Export::Gif) => fs::create_dir_all(&dir)
+                    .and_then(|_| animate::export_gif(&frames, &dir.join("animation.gif"), scale as usize, delay))
+                    .map(|_| Some(dir.join("animation.gif").display().to_string())),
+                (Some(dir), image) => {
+                    let image = if image == Export::Pgm { animate::Image::Pgm } else { animate::Image::Ppm };
+                    animate::export(&frames, &dir, image, scale as usize)
+                        .map(|written| Some(format!("{} frames into {}", written.len(), dir.display())))
+                }
+            };
+            match shown {
+                Ok(Some(saved)) => println!("Saved {}", saved),
+                Ok(None) => {}
+                Err(error) => {
+                    eprintln!("Can't show the frames: {}", error);
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Repl { day } => {
+            if let Err(error) = repl(day) {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        }
+        #[cfg(feature = "server")]
+        Command::Serve { port, jobs } => {
+            let server = aoc_rust::server::Server::bind(("127.0.0.1", port)).unwrap_or_else(|error| {
+                eprintln!("Can't listen on port {}: {}", port, error);
+                process::exit(1);
+            });
+            println!("Listening on http://{}", server.address());
+            server.serve(jobs as usize);
+        }
+        Command::Cache { action } => {
+            let mut cache = Cache::open_default().unwrap_or_else(|error| {
+                eprintln!("Can't read the answer cache: {}", error);
+                process::exit(1);
+            });
+            match action {
+                CacheAction::List => cache.entries().iter().for_each(|entry| println!("Day {} part {}: {} (solver {}, input {} {})",
+                    entry.day, entry.part, entry.answer, entry.version, &entry.input[..12.min(entry.input.len())], entry.path)),
+                CacheAction::Clear { day } => {
+                    let cleared = cache.clear(day);
+                    if let Err(error) = cache.save() {
+                        eprintln!("Can't save the answer cache: {}", error);
+                        process::exit(1);
+                    }
+                    println!("Cleared {} cached answers", cleared);
+                }
+            }
+        }
     }
 }
 
+/// Reads lines with editing and history until Ctrl-D or Ctrl-C
+fn repl(day: u8) -> Result<(), String> {
+    let mut repl = Repl::new(day).ok_or_else(|| format!("Day {} has no language to type, try 8, 14 or 18", day))?;
+    let mut editor = DefaultEditor::new().map_err(|error| error.to_string())?;
+    println!("Day {}, {}", day, repl::HELP);
+    loop {
+        match editor.readline(&format!("day{}> ", day)) {
+            Ok(line) => {
+                let _ = editor.add_history_entry(line.as_str());
+                match repl.line(&line) {
+                    Ok(output) if output.is_empty() => {}
+                    Ok(output) => println!("{}", output),
+                    Err(error) => eprintln!("{}", error),
+                }
+            }
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => return Ok(()),
+            Err(error) => return Err(error.to_string()),
+        }
+    }
+}
+
+/// One answer, `solved` holds the timings unless the answer came from the cache
+fn print_answer(format: Format, report: &Report, answer: &str, solved: Option<&Solved>, file: &Path) {
+    match format {
+        Format::Text => println!("Day {} part {}: {}", report.day, report.part, answer),
+        Format::Json => println!("{}", json!({
+            "day": report.day,
+            "part": report.part,
+            "answer": answer,
+            "parse_ns": solved.map_or(0, |solved| solved.parse.as_nanos() as u64),
+            "solve_ns": solved.map_or(0, |solved| solved.solve.as_nanos() as u64),
+            "input_path": file.display().to_string(),
+            "cached": solved.is_none(),
+        })),
+    }
+}
+
+/// Every file in `dir`, by name. Subdirectories and hidden files are left out.
+fn dir_inputs(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() && !entry.file_name().to_string_lossy().starts_with('.') {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// The checked-in answers, none when `answers.txt` is missing or malformed
+fn known_answers() -> Vec<answers::Answer> {
+    match fs::read_to_string(answers::ANSWERS).map(|text| answers::parse(&text)) {
+        Ok(Ok(known)) => known,
+        Ok(Err(error)) => {
+            eprintln!("{}: {}", answers::ANSWERS, error);
+            Vec::new()
+        }
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Paths naming the same file, however they're spelled
+fn same_file(one: &Path, other: &Path) -> bool {
+    match (one.canonicalize(), other.canonicalize()) {
+        (Ok(one), Ok(other)) => one == other,
+        _ => one == other,
+    }
+}
+
+/// Table of each input file with the answers of both parts, marking the ones checked against `known` answers.
+/// Also counts the answers that differ from the known ones.
+fn inputs_table(targets: &[(&Puzzle, PathBuf)], reports: &[Vec<Report>], known: &[answers::Answer]) -> (String, usize) {
+    let mut table = format!("{:<24} {:<32} {:<32}\n", "input", "part 1", "part 2");
+    let (mut checked, mut wrong) = (0, 0);
+    for ((puzzle, file), reports) in targets.iter().zip(reports) {
+        let mut cells = ["-".to_string(), "-".to_string()];
+        for report in reports {
+            let answer = match &report.outcome {
+                Outcome::Solved(solved) => &solved.answer,
+                Outcome::Cached(answer) => answer,
+                Outcome::Malformed(_) => {
+                    cells[report.part as usize - 1] = "malformed input".to_string();
+                    continue;
+                }
+                Outcome::NoAnswer(_) => {
+                    cells[report.part as usize - 1] = "no answer".to_string();
+                    continue;
+                }
+                Outcome::Panicked(_) => {
+                    cells[report.part as usize - 1] = "panicked".to_string();
+                    continue;
+                }
+            };
+            let expected = known.iter()
+                .find(|known| known.day == puzzle.day && known.part == report.part && same_file(&known.input, file));
+            cells[report.part as usize - 1] = match expected {
+                Some(expected) if expected.answer == *answer => {
+                    checked += 1;
+                    format!("{} (ok)", answer)
+                }
+                Some(expected) => {
+                    checked += 1;
+                    wrong += 1;
+                    format!("{} (expected {})", answer, expected.answer)
+                }
+                None => answer.clone(),
+            };
+        }
+        let name = file.file_name().map_or_else(|| file.display().to_string(), |name| name.to_string_lossy().to_string());
+        table.push_str(&format!("{:<24} {:<32} {:<32}\n", name, cells[0], cells[1]));
+    }
+    table.push_str(&format!("{} inputs, {} answers checked, {} wrong", targets.len(), checked, wrong));
+    (table, wrong)
+}
 
-#[cfg(test)]
-mod tests {
-    use crate::Data;
+/// The day's puzzle input, then every other input `answers.txt` knows answers of the day for
+fn day_inputs(puzzle: &Puzzle) -> Vec<PathBuf> {
+    let known = known_answers();
+    let mut files = vec![PathBuf::from(puzzle.input)];
+    for known in known.into_iter().filter(|known| known.day == puzzle.day) {
+        if !files.contains(&known.input) {
+            files.push(known.input);
+        }
+    }
+    files
+}
 
-    #[test]
-    fn one() {
-        let data = Data { ..Default::default() };
-        assert!(data.part_one().contains(&(898299 as u32)))
+/// Solves `parts` of the day for the input in `file`, printing answers with their timings or why there are none
+fn solve_file(puzzle: &'static Puzzle, parts: &[u8], file: &Path) {
+    println!("== {}", file.display());
+    let text = match input::open(file).and_then(input::read) {
+        Ok(text) => text,
+        Err(error) => {
+            println!("Can't read input: {}", error);
+            return;
+        }
+    };
+    let job = Job { puzzle, input: text, parts: parts.to_vec() };
+    for report in runner::run(vec![job], 1, |_, _| ()).into_iter().flatten() {
+        match report.outcome {
+            Outcome::Solved(solved) => println!("Day {} part {}: {} (parse {:.1?}, solve {:.1?})",
+                report.day, report.part, solved.answer, solved.parse, solved.solve),
+            Outcome::Cached(answer) => println!("Day {} part {}: {}", report.day, report.part, answer),
+            Outcome::Malformed(error) => println!("Day {} part {}: malformed input: {}", report.day, report.part, error),
+            Outcome::NoAnswer(error) => println!("Day {} part {}: {}", report.day, report.part, error),
+            Outcome::Panicked(message) => println!("Day {} part {}: panicked: {}", report.day, report.part, message),
+        }
     }
+}
 
-    #[test]
-    fn two() {
-        let data = Data { ..Default::default() };
-        assert!(data.part_two() == 143933922)
+/// Solves every known answer (of `day`, when given) and reports pass, fail or missing answers. True when nothing failed.
+fn verify(day: Option<u8>, path: &Path) -> bool {
+    let known = fs::read_to_string(path).map_err(|error| error.to_string())
+        .and_then(|text| answers::parse(&text).map_err(|error| error.to_string()));
+    let known = match known {
+        Ok(known) => known,
+        Err(error) => {
+            eprintln!("{}: {}", path.display(), error);
+            return false;
+        }
+    };
+    let wanted = |checked: u8| day.is_none_or(|day| day == checked);
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for known in known.iter().filter(|known| wanted(known.day)) {
+        let label = format!("Day {} part {} {}", known.day, known.part, known.input.display());
+        let puzzle = match puzzle(known.day) {
+            Some(puzzle) => puzzle,
+            None => {
+                println!("{}: FAIL, no solution for this day", label);
+                failed += 1;
+                continue;
+            }
+        };
+        let text = match input::open(&known.input).and_then(input::read) {
+            Ok(text) => text,
+            Err(error) => {
+                println!("{}: FAIL, can't read input: {}", label, error);
+                failed += 1;
+                continue;
+            }
+        };
+        match puzzle.solve(&text, known.part) {
+            Ok(solved) if solved.answer == known.answer => {
+                println!("{}: pass in {:?}", label, solved.parse + solved.solve);
+                passed += 1;
+            }
+            Ok(solved) => {
+                println!("{}: FAIL in {:?}, expected {} but got {}", label, solved.parse + solved.solve, known.answer, solved.answer);
+                failed += 1;
+            }
+            Err(error) => {
+                println!("{}: FAIL, {}", label, error);
+                failed += 1;
+            }
+        }
+    }
+    for puzzle in CALENDAR.iter().filter(|puzzle| wanted(puzzle.day)) {
+        for part in 1..=2 {
+            if !known.iter().any(|known| known.day == puzzle.day && known.part == part && known.input == Path::new(puzzle.input)) {
+                println!("Day {} part {} {}: missing answer", puzzle.day, part, puzzle.input);
+                missing += 1;
+            }
+        }
     }
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    failed == 0
 }
@@ -0,0 +1,123 @@
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::day8::assembler::Console;
+use crate::day14::Docking;
+use crate::day18::Homework;
+use crate::error::ParseError;
+use crate::runner;
+
+/// A day's little language, run a line at a time
+pub trait Session {
+    /// Runs a single line, tells what it did
+    fn eval(&mut self, line: &str) -> Result<String, ParseError>;
+    /// Everything the session holds, for `:state`
+    fn state(&self) -> String;
+}
+
+/// Fresh session of a day with a language, days 8, 14 and 18 have one
+pub fn session(day: u8) -> Option<Box<dyn Session>> {
+    match day {
+        8 => Some(Box::new(Console::default())),
+        14 => Some(Box::new(Docking::default())),
+        18 => Some(Box::new(Homework::default())),
+        _ => None,
+    }
+}
+
+pub const HELP: &str = ":state shows the state, :reset starts over, :load FILE runs every line of FILE, :help shows this";
+
+/// Lines of a day's language mixed with `:commands`
+pub struct Repl {
+    day: u8,
+    session: Box<dyn Session>,
+}
+
+impl Repl {
+    pub fn new(day: u8) -> Option<Repl> {
+        Some(Repl { day, session: session(day)? })
+    }
+
+    /// Answers a line: a `:command` or a line of the day's language
+    pub fn line(&mut self, line: &str) -> Result<String, String> {
+        let line = line.trim();
+        match line.split_once(' ').unwrap_or((line, "")) {
+            ("", _) => Ok(String::new()),
+            (":state", _) => Ok(self.session.state()),
+            (":reset", _) => {
+                self.session = session(self.day).expect("a session for the day");
+                Ok("Started over".to_string())
+            }
+            (":load", "") => Err("Load what? :load FILE".to_string()),
+            (":load", file) => self.load(file.trim()),
+            (":help", _) => Ok(HELP.to_string()),
+            (command, _) if command.starts_with(':') => Err(format!("No command {}, {}", command, HELP)),
+            _ => self.eval(line).map_err(|error| error.to_string()),
+        }
+    }
+
+    /// Runs the non-blank lines of `file`, stops at the first malformed one
+    fn load(&mut self, file: &str) -> Result<String, String> {
+        let text = fs::read_to_string(file).map_err(|error| format!("Can't read {}: {}", file, error))?;
+        let mut last = String::new();
+        let mut count = 0;
+        for (index, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            last = self.eval(line).map_err(|error| format!("{}: {}", file, ParseError { line: index + 1, ..error }))?;
+            count += 1;
+        }
+        Ok(format!("Ran {} lines of {}\n{}", count, file, last))
+    }
+
+    /// A panicking line is an error too, the state may be half updated then
+    fn eval(&mut self, line: &str) -> Result<String, ParseError> {
+        match panic::catch_unwind(AssertUnwindSafe(|| self.session.eval(line))) {
+            Ok(result) => result,
+            Err(payload) => Err(ParseError::new(line, line, format!("a line that doesn't panic ({}), :reset may be needed",
+                runner::message(payload)))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Repl;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn day_eight() {
+        let mut repl = Repl::new(8).unwrap();
+        assert_eq!(repl.line("acc +3").unwrap(), "accumulator 3, next instruction 1");
+        assert_eq!(repl.line("jmp -1").unwrap(), "looped at instruction 0, accumulator 3");
+        assert!(repl.line(":state").unwrap().contains("\n>  0 acc +3   ran 1\n   1 jmp -1   ran 1"));
+        assert_eq!(repl.line("mul +2").unwrap_err(), "line 1, column 1: expected `acc`, `jmp` or `nop`, found \"mul\"");
+        repl.line(":reset").unwrap();
+        assert_eq!(repl.line("nop +0").unwrap(), "accumulator 0, next instruction 1");
+        assert!(repl.line(":nope").is_err());
+        assert!(Repl::new(1).is_none());
+    }
+
+    #[test]
+    fn day_fourteen() {
+        let mut repl = Repl::new(14).unwrap();
+        repl.line("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X").unwrap();
+        assert_eq!(repl.line("mem[8] = 11").unwrap(), "sum 73 (version 1), skipped version 2 with 34 floating bits");
+        let state = repl.line(":state").unwrap();
+        assert!(state.contains("mask XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X"));
+        assert!(state.contains("mem[8] = 73"));
+        repl.line("mask = 000000000000000000000000000000X1001X").unwrap();
+        assert_eq!(repl.line("mem[42] = 100").unwrap(), "sum 123 (version 1), 400 (version 2)");
+    }
+
+    #[test]
+    fn day_eighteen_loads_files() {
+        let file = env::temp_dir().join(format!("aoc-repl-test-{}.txt", std::process::id()));
+        fs::write(&file, "2 * 3 + (4 * 5)\n\n5 + (8 * 3 + 9 + 3 * 4 * 3)\n1 + x").unwrap();
+        let mut repl = Repl::new(18).unwrap();
+        let error = repl.line(&format!(":load {}", file.display())).unwrap_err();
+        assert!(error.ends_with(": line 4, column 5: expected a digit or `(`, found \"x\""), "{}", error);
+        assert!(repl.line(":state").unwrap().starts_with("2 lines, sums 463 left to right, 1491 with addition first"));
+        fs::remove_file(&file).unwrap();
+        assert_eq!(repl.line("2 * 3 + (4 * 5)").unwrap(), "26 left to right, 46 with addition first");
+    }
+}
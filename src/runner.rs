@@ -0,0 +1,170 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::error::{ParseError, NoAnswer, SolveError};
+use crate::solution::{Puzzle, Solved};
+
+/// A day to solve: the puzzle, its input and the parts wanted
+pub struct Job {
+    pub puzzle: &'static Puzzle,
+    pub input: String,
+    pub parts: Vec<u8>,
+}
+
+/// How solving a part ended
+#[derive(Debug)]
+pub enum Outcome {
+    Solved(Solved),
+    /// Answer solved by an earlier run, see [`crate::cache`]
+    Cached(String),
+    Malformed(ParseError),
+    /// The input is well formed but the part has no answer for it
+    NoAnswer(NoAnswer),
+    /// The solver panicked, with the panic message
+    Panicked(String),
+}
+
+/// A single part of a day, `time` counts until it was solved or failed
+#[derive(Debug)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub time: Duration,
+    pub outcome: Outcome,
+}
+
+impl Report {
+    pub fn failed(&self) -> bool {
+        !matches!(self.outcome, Outcome::Solved(_) | Outcome::Cached(_))
+    }
+}
+
+/// Solves every job on up to `threads` threads, a day at a time on each of them.
+/// `done` sees the index of each job with its reports together as soon as the job is over, so their output never interleaves.
+/// A panic only fails the part it happened in. The reports come back in the order of `jobs`.
+pub fn run<F>(jobs: Vec<Job>, threads: usize, mut done: F) -> Vec<Vec<Report>> where F: FnMut(usize, &[Report]) {
+    let next = AtomicUsize::new(0);
+    let mut reports = jobs.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+    thread::scope(|scope| {
+        let (sender, receiver) = mpsc::channel();
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            let (sender, next, jobs) = (sender.clone(), &next, &jobs);
+            scope.spawn(move || {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let job = match jobs.get(index) {
+                        Some(job) => job,
+                        None => break,
+                    };
+                    let day = job.parts.iter().map(|&part| solve(job, part)).collect::<Vec<_>>();
+                    if sender.send((index, day)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+        for (index, finished) in receiver {
+            done(index, &finished);
+            reports[index] = finished;
+        }
+    });
+    reports
+}
+
+fn solve(job: &Job, part: u8) -> Report {
+    let start = Instant::now();
+    let outcome = match panic::catch_unwind(AssertUnwindSafe(|| job.puzzle.solve(&job.input, part))) {
+        Ok(Ok(solved)) => Outcome::Solved(solved),
+        Ok(Err(SolveError::Malformed(error))) => Outcome::Malformed(error),
+        Ok(Err(SolveError::NoAnswer(error))) => Outcome::NoAnswer(error),
+        Err(payload) => Outcome::Panicked(message(payload)),
+    };
+    Report { day: job.puzzle.day, part, time: start.elapsed(), outcome }
+}
+
+pub(crate) fn message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or("unknown cause", |message| message).to_string(),
+    }
+}
+
+/// Table of every part with its answer or failure and the time it took, then totals. `wall` is how long the whole run took.
+pub fn summary(reports: &[Report], wall: Duration) -> String {
+    let mut table = format!("{:>3} {:>4}  {:<24} {:>10}\n", "day", "part", "answer", "time");
+    for report in reports {
+        let result = match &report.outcome {
+            Outcome::Solved(solved) => solved.answer.clone(),
+            Outcome::Cached(answer) => format!("{} (cached)", answer),
+            Outcome::Malformed(error) => format!("malformed input: {}", error),
+            Outcome::NoAnswer(error) => error.to_string(),
+            Outcome::Panicked(message) => format!("panicked: {}", message),
+        };
+        table.push_str(&format!("{:>3} {:>4}  {:<24} {:>10}\n", report.day, report.part, result, format!("{:.1?}", report.time)));
+    }
+    let failed = reports.iter().filter(|report| report.failed()).count();
+    let busy = reports.iter().map(|report| report.time).sum::<Duration>();
+    table.push_str(&format!("{} solved, {} failed in {:.1?} ({:.1?} of solving)", reports.len() - failed, failed, wall, busy));
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run, summary, Job, Outcome};
+    use crate::error::{ParseError, NoAnswer};
+    use crate::generate::Rng;
+    use crate::solution::{puzzle, Puzzle, Solution};
+    use std::time::Duration;
+
+    /// Solver for day 0 whose first part always panics
+    struct Panics;
+
+    impl Solution for Panics {
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "";
+        type Input = ();
+        type PartOne = u8;
+        type PartTwo = u8;
+
+        fn parse(_input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+        fn part_one(_input: &()) -> Result<u8, NoAnswer> {
+            panic!("no answer to part one")
+        }
+        fn part_two(_input: &()) -> Result<u8, NoAnswer> {
+            Ok(2)
+        }
+        fn generate(_rng: &mut Rng, _size: usize) -> String {
+            String::new()
+        }
+    }
+
+    static PANICS: Puzzle = Puzzle::of::<Panics>();
+
+    #[test]
+    fn panic_fails_only_its_part() {
+        let jobs = vec![
+            Job { puzzle: &PANICS, input: String::new(), parts: vec![1, 2] },
+            Job { puzzle: puzzle(1).unwrap(), input: "1721\n979\n366\n299\n675\n1456".to_string(), parts: vec![1] },
+            Job { puzzle: puzzle(2).unwrap(), input: "1-3 a abcde".to_string(), parts: vec![1] },
+        ];
+        let mut days = Vec::new();
+        let reports = run(jobs, 2, |index, finished| days.push((finished[0].day, index)));
+        days.sort_unstable();
+        assert_eq!(days, vec![(0, 0), (1, 1), (2, 2)]);
+        assert_eq!(reports.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 1, 1]);
+        assert!(matches!(&reports[0][0].outcome, Outcome::Panicked(message) if message == "no answer to part one"));
+        assert!(matches!(&reports[0][1].outcome, Outcome::Solved(solved) if solved.answer == "2"));
+        assert!(matches!(&reports[1][0].outcome, Outcome::Solved(solved) if solved.answer == "514579"));
+        assert!(matches!(reports[2][0].outcome, Outcome::Malformed(_)));
+        let table = summary(&reports.into_iter().flatten().collect::<Vec<_>>(), Duration::from_millis(3));
+        assert!(table.contains("  1    1  514579"));
+        assert!(table.contains("2 solved, 2 failed in 3.0ms"));
+    }
+}
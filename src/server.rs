@@ -0,0 +1,122 @@
+use std::error::Error;
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::thread;
+
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response};
+use tracing::{debug, warn};
+
+use crate::runner::{self, Job, Outcome};
+use crate::solution::puzzle;
+
+/// HTTP front of the solvers: `POST /day/{n}/part/{p}` with the puzzle input as the body answers
+/// `{day, part, answer, parse_ns, solve_ns}`, failures are a JSON `{error, ...}` with a 4xx or 5xx status.
+pub struct Server {
+    http: tiny_http::Server,
+}
+
+impl Server {
+    pub fn bind<A: ToSocketAddrs>(address: A) -> Result<Server, Box<dyn Error + Send + Sync>> {
+        Ok(Server { http: tiny_http::Server::http(address)? })
+    }
+
+    /// Where the server listens, tells the port when bound to port 0
+    pub fn address(&self) -> SocketAddr {
+        self.http.server_addr().to_ip().expect("listening on a TCP socket")
+    }
+
+    /// Answers requests on `threads` threads, a request at a time on each of them, for as long as the server lives
+    pub fn serve(&self, threads: usize) {
+        thread::scope(|scope| {
+            for _ in 0..threads.max(1) {
+                scope.spawn(|| {
+                    for request in self.http.incoming_requests() {
+                        handle(request);
+                    }
+                });
+            }
+        });
+    }
+}
+
+fn handle(mut request: Request) {
+    let mut body = String::new();
+    let (status, json) = match request.as_reader().read_to_string(&mut body) {
+        Ok(_) => respond(request.method(), request.url(), &body),
+        Err(error) => (400, json!({ "error": format!("body is not UTF-8 text: {}", error) })),
+    };
+    debug!(method = %request.method(), url = request.url(), status, "answered");
+    let response = Response::from_string(json.to_string())
+        .with_status_code(status)
+        .with_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+    if let Err(error) = request.respond(response) {
+        warn!(%error, "can't send the response");
+    }
+}
+
+/// Status and JSON body answering `method` on `url`, with `body` as the puzzle input
+pub fn respond(method: &Method, url: &str, body: &str) -> (u16, Value) {
+    let path = url.split('?').next().unwrap_or_default();
+    let (day, part) = match path.split('/').collect::<Vec<_>>()[..] {
+        ["", "day", day, "part", part] => (day, part),
+        _ => return (404, json!({ "error": format!("no such endpoint {}, try POST /day/{{n}}/part/{{p}}", path) })),
+    };
+    if *method != Method::Post {
+        return (405, json!({ "error": "only POST is allowed, with the puzzle input as the body" }));
+    }
+    let puzzle = match day.parse().ok().and_then(puzzle) {
+        Some(puzzle) => puzzle,
+        None => return (404, json!({ "error": format!("no solution for day {}", day) })),
+    };
+    let part = match part {
+        "1" => 1,
+        "2" => 2,
+        _ => return (404, json!({ "error": format!("no part {}, only 1 or 2", part) })),
+    };
+    let job = Job { puzzle, input: body.to_string(), parts: vec![part] };
+    let report = runner::run(vec![job], 1, |_, _| ()).into_iter().flatten().next().expect("a report for the part");
+    match report.outcome {
+        Outcome::Solved(solved) => (200, json!({
+            "day": puzzle.day,
+            "part": part,
+            "answer": solved.answer,
+            "parse_ns": solved.parse.as_nanos() as u64,
+            "solve_ns": solved.solve.as_nanos() as u64,
+        })),
+        Outcome::Cached(answer) => (200, json!({ "day": puzzle.day, "part": part, "answer": answer })),
+        Outcome::Malformed(error) => (422, json!({
+            "error": "malformed input",
+            "message": error.to_string(),
+            "line": error.line,
+            "column": error.column,
+            "text": error.text,
+            "expected": error.expected,
+        })),
+        Outcome::NoAnswer(error) => (422, json!({
+            "error": "no answer",
+            "message": error.to_string(),
+            "expected": error.expected,
+        })),
+        Outcome::Panicked(message) => (500, json!({ "error": "solver panicked", "message": message })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::respond;
+    use tiny_http::Method;
+
+    #[test]
+    fn routes() {
+        let (status, answer) = respond(&Method::Post, "/day/1/part/1", "1721\n979\n366\n299\n675\n1456");
+        assert_eq!((status, answer["answer"].as_str()), (200, Some("514579")));
+        let (status, error) = respond(&Method::Post, "/day/1/part/2", "1721\nx");
+        assert_eq!((status, error["line"].as_u64(), error["expected"].as_str()), (422, Some(2), Some("a number")));
+        assert_eq!(respond(&Method::Get, "/day/1/part/1", "").0, 405);
+        assert_eq!(respond(&Method::Post, "/day/99/part/1", "").0, 404);
+        assert_eq!(respond(&Method::Post, "/day/1/part/3", "").0, 404);
+        assert_eq!(respond(&Method::Post, "/days", "").0, 404);
+        let (status, error) = respond(&Method::Post, "/day/5/part/1", "");
+        assert_eq!((status, error["error"].as_str(), error["expected"].as_str()), (422, Some("no answer"), Some("at least one boarding pass")));
+    }
+}
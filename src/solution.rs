@@ -0,0 +1,223 @@
+use std::fmt::Display;
+use std::process;
+use std::time::{Duration, Instant};
+use tracing::level_filters::LevelFilter;
+
+use crate::error::{ParseError, NoAnswer, SolveError};
+use crate::generate::{self, Rng};
+use crate::{input, logging};
+use crate::day1::Day1;
+use crate::day2::Day2;
+use crate::day3::Day3;
+use crate::day4::Day4;
+use crate::day5::Day5;
+use crate::day6::Day6;
+use crate::day7::Day7;
+use crate::day8::Day8;
+use crate::day9::Day9;
+use crate::day10::Day10;
+use crate::day11::Day11;
+use crate::day12::Day12;
+use crate::day13::Day13;
+use crate::day14::Day14;
+use crate::day15::Day15;
+use crate::day16::Day16;
+use crate::day17::Day17;
+use crate::day18::Day18;
+use crate::day19::Day19;
+use crate::day20::Day20;
+use crate::day21::Day21;
+use crate::day22::Day22;
+use crate::day23::Day23;
+
+/// A single day of the calendar. The puzzle input is parsed once and both parts are solved from it.
+pub trait Solution {
+    const DAY: u8;
+    /// Checked-in puzzle input, relative to the repository root
+    const INPUT: &'static str;
+    /// Bump when a change to the solver can change its answers, cached answers of older revisions are then ignored
+    const REVISION: u32 = 1;
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    /// Checks the input is well formed, whether it has answers is up to the parts
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, NoAnswer>;
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, NoAnswer>;
+    /// Random, well formed input of about `size` records: lines, passports, rules or the side of a map
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Answer to one part, normalized to its `Display` text whatever the day's answer type, with the time it took
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Type erased [`Solution`], so days can be picked at runtime
+pub struct Puzzle {
+    pub day: u8,
+    pub input: &'static str,
+    pub revision: u32,
+    solve: fn(&str, u8) -> Result<Solved, SolveError>,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Puzzle {
+    pub const fn of<S: Solution>() -> Puzzle {
+        Puzzle {
+            day: S::DAY,
+            input: S::INPUT,
+            revision: S::REVISION,
+            solve: solve::<S>,
+            generate: S::generate,
+        }
+    }
+    pub fn solve(&self, input: &str, part: u8) -> Result<Solved, SolveError> {
+        (self.solve)(input, part)
+    }
+    /// Version of the solver that answers are cached under, the crate version and the day's revision
+    pub fn version(&self) -> String {
+        format!("{}+{}", env!("CARGO_PKG_VERSION"), self.revision)
+    }
+    /// Same `seed` and `size` give the same input
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        (self.generate)(&mut generate::rng(seed), size)
+    }
+}
+
+fn solve<S: Solution>(input: &str, part: u8) -> Result<Solved, SolveError> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parsed = Instant::now();
+    let answer = match part {
+        1 => S::part_one(&input)?.to_string(),
+        _ => S::part_two(&input)?.to_string(),
+    };
+    Ok(Solved {
+        answer,
+        parse: parsed - start,
+        solve: parsed.elapsed(),
+    })
+}
+
+pub static CALENDAR: [Puzzle; 23] = [
+    Puzzle::of::<Day1>(),
+    Puzzle::of::<Day2>(),
+    Puzzle::of::<Day3>(),
+    Puzzle::of::<Day4>(),
+    Puzzle::of::<Day5>(),
+    Puzzle::of::<Day6>(),
+    Puzzle::of::<Day7>(),
+    Puzzle::of::<Day8>(),
+    Puzzle::of::<Day9>(),
+    Puzzle::of::<Day10>(),
+    Puzzle::of::<Day11>(),
+    Puzzle::of::<Day12>(),
+    Puzzle::of::<Day13>(),
+    Puzzle::of::<Day14>(),
+    Puzzle::of::<Day15>(),
+    Puzzle::of::<Day16>(),
+    Puzzle::of::<Day17>(),
+    Puzzle::of::<Day18>(),
+    Puzzle::of::<Day19>(),
+    Puzzle::of::<Day20>(),
+    Puzzle::of::<Day21>(),
+    Puzzle::of::<Day22>(),
+    Puzzle::of::<Day23>(),
+];
+
+pub fn puzzle(day: u8) -> Option<&'static Puzzle> {
+    CALENDAR.iter().find(|puzzle| puzzle.day == day)
+}
+
+/// Solves both parts for the file named on the command line (`-` for stdin), or for the day's checked-in input.
+/// Only warnings are logged, `RUST_LOG` turns on more.
+pub fn print_answers<S: Solution>() {
+    logging::init(LevelFilter::WARN);
+    let text = match input::from_args(S::INPUT) {
+        Ok(text) => text,
+        Err(error) => {
+            eprintln!("Can't read day {} input: {}", S::DAY, error);
+            process::exit(1);
+        }
+    };
+    let input = match S::parse(&text) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Day {} input is malformed: {}", S::DAY, error);
+            process::exit(1);
+        }
+    };
+    let answers = [S::part_one(&input).map(|answer| answer.to_string()), S::part_two(&input).map(|answer| answer.to_string())];
+    for (part, answer) in (1..).zip(&answers) {
+        match answer {
+            Ok(answer) => println!("Day {} part {}: {}", S::DAY, part, answer),
+            Err(error) => eprintln!("Day {} part {}: {}", S::DAY, part, error),
+        }
+    }
+    if answers.iter().any(Result::is_err) {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{puzzle, CALENDAR};
+    use crate::error::{NoAnswer, SolveError};
+
+    #[test]
+    fn solves_by_day() {
+        let day1 = puzzle(1).unwrap();
+        let input = "1721\n979\n366\n299\n675\n1456";
+        assert_eq!(day1.solve(input, 1).unwrap().answer, "514579");
+        assert_eq!(day1.solve(input, 2).unwrap().answer, "241861950");
+        assert!(matches!(day1.solve("1721\nx", 1), Err(SolveError::Malformed(error)) if error.line == 2));
+    }
+
+    #[test]
+    fn inputs_without_an_answer() {
+        fn error(day: u8, part: u8, input: &str) -> SolveError {
+            puzzle(day).unwrap().solve(input, part).unwrap_err()
+        }
+        assert_eq!(error(1, 1, "1721\n979").to_string(), "no answer, expected two entries that sum to 2020");
+        assert_eq!(puzzle(1).unwrap().solve("1721\n979\n299", 1).unwrap().answer, "514579");
+        assert_eq!(error(1, 2, "1721\n979\n299"), SolveError::NoAnswer(NoAnswer::new("three entries that sum to 2020")));
+        assert_eq!(error(5, 1, "").to_string(), "no answer, expected at least one boarding pass");
+        assert_eq!(error(5, 2, "FBFBBFFRLR\nFBFBBFFRRL").to_string(), "no answer, expected a free seat between taken ones");
+        let sums = (1..=30).map(|n| n.to_string()).collect::<Vec<_>>().join("\n");
+        assert_eq!(error(9, 2, &sums).to_string(), "no answer, expected a number that isn't the sum of two of the 45 before it");
+        assert_eq!(error(10, 1, "1\n2\n9\n7"), error(10, 2, "1\n2\n9\n7"));
+        assert_eq!(error(13, 1, "939\nx,x").to_string(), "no answer, expected at least one bus in service");
+        assert!(matches!(error(13, 2, "939\nx,y"), SolveError::Malformed(_)));
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        for puzzle in CALENDAR.iter() {
+            assert_eq!(puzzle.generate(10, 3), puzzle.generate(10, 3), "day {}", puzzle.day);
+            // Thirty million turns of day 15, ten million moves of day 23 and a big four dimensional pocket are
+            // too slow for a debug build
+            let size = if puzzle.day == 17 { 3 } else { 10 };
+            let parts = if puzzle.day == 15 || puzzle.day == 23 { 1..=1 } else { 1..=2 };
+            for seed in 0..3 {
+                let input = puzzle.generate(size, seed);
+                for part in parts.clone() {
+                    if let Err(error) = puzzle.solve(&input, part) {
+                        panic!("day {} seed {} part {}: {}\n{}", puzzle.day, seed, part, error, input);
+                    }
+                }
+            }
+        }
+        // More starting numbers than day 15 generates, the generator caps them below the 2020 turns of part one
+        let day15 = puzzle(15).unwrap();
+        let input = day15.generate(5000, 0);
+        assert_eq!(input.split(',').count(), 2019);
+        assert!(day15.solve(&input, 1).is_ok());
+        let input = (0..5000).map(|number| number.to_string()).collect::<Vec<_>>().join(",");
+        assert_eq!(day15.solve(&input, 1).unwrap().answer, "2019");
+    }
+}
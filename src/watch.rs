@@ -0,0 +1,85 @@
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+/// Events this close together are one change, editors often write a file in several steps
+const SETTLE: Duration = Duration::from_millis(100);
+
+/// Calls `changed` with each of `files` that was written, as it was given, until it breaks or watching fails.
+/// The directories holding the files are watched rather than the files, so replacing a file (as many editors save) is still seen.
+pub fn watch<F>(files: &[PathBuf], mut changed: F) -> notify::Result<()> where F: FnMut(&Path) -> ControlFlow<()> {
+    let absolute = files.iter().map(|file| absolute(file)).collect::<Result<Vec<_>, _>>()?;
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = RecommendedWatcher::new(sender, notify::Config::default())?;
+    let mut directories = absolute.iter().filter_map(|file| file.parent()).collect::<Vec<_>>();
+    directories.sort();
+    directories.dedup();
+    for directory in directories {
+        watcher.watch(directory, RecursiveMode::NonRecursive)?;
+    }
+    let mut written = Vec::new();
+    while let Ok(event) = receiver.recv() {
+        written.push(event?);
+        while let Ok(event) = receiver.recv_timeout(SETTLE) {
+            written.push(event?);
+        }
+        let touched = |file: &PathBuf| written.iter()
+            .filter(|event| matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)))
+            .any(|event| event.paths.contains(file));
+        for (file, _) in files.iter().zip(absolute.iter()).filter(|(_, absolute)| touched(absolute)) {
+            if changed(file).is_break() {
+                return Ok(());
+            }
+        }
+        written.clear();
+    }
+    Ok(())
+}
+
+/// `file` with an absolute, symlink free directory, the way watch events name it. The file itself may not exist yet.
+fn absolute(file: &Path) -> notify::Result<PathBuf> {
+    let name = file.file_name().ok_or_else(|| notify::Error::generic(&format!("{} is not a file", file.display())))?;
+    let directory = match file.parent() {
+        Some(parent) if parent != Path::new("") => parent,
+        _ => Path::new("."),
+    };
+    Ok(directory.canonicalize()?.join(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::watch;
+    use std::env;
+    use std::fs;
+    use std::ops::ControlFlow;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn sees_writes_to_watched_files_only() {
+        let dir = env::temp_dir().join(format!("aoc-watch-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (watched, other) = (dir.join("input.dat"), dir.join("other.dat"));
+        fs::write(&watched, "1").unwrap();
+        let writer = {
+            let (watched, other) = (watched.clone(), other.clone());
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(300));
+                fs::write(other, "2").unwrap();
+                thread::sleep(Duration::from_millis(300));
+                fs::write(watched, "3").unwrap();
+            })
+        };
+        let mut seen = Vec::new();
+        watch(&[watched], |file| {
+            seen.push(fs::read_to_string(file).unwrap());
+            ControlFlow::Break(())
+        }).unwrap();
+        writer.join().unwrap();
+        assert_eq!(seen, vec!["3"]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
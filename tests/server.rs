@@ -0,0 +1,67 @@
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::thread;
+
+use aoc_rust::server::Server;
+use serde_json::Value;
+
+/// Starts a server on a free port, it lives until the test process ends
+fn start() -> SocketAddr {
+    let server = Server::bind("127.0.0.1:0").unwrap();
+    let address = server.address();
+    thread::spawn(move || server.serve(2));
+    address
+}
+
+/// Sends a request over a fresh connection, returns the status and the JSON body
+fn request(address: SocketAddr, method: &str, path: &str, body: &str) -> (u16, Value) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(stream, "{} {} HTTP/1.1\r\nHost: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        method, path, address, body.len(), body).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+    (status, serde_json::from_str(body).unwrap())
+}
+
+#[test]
+fn solves_posted_inputs() {
+    let address = start();
+    let (status, answer) = request(address, "POST", "/day/16/part/1",
+        "class: 1-3 or 5-7\nrow: 6-11 or 33-44\nseat: 13-40 or 45-50\n\nyour ticket:\n7,1,14\n\nnearby tickets:\n7,3,47\n40,4,50\n55,2,20\n38,6,12");
+    assert_eq!(status, 200);
+    assert_eq!(answer["day"], 16);
+    assert_eq!(answer["part"], 1);
+    assert_eq!(answer["answer"], "71");
+    assert!(answer["solve_ns"].is_u64());
+
+    let (status, answer) = request(address, "POST", "/day/18/part/2", "2 * 3 + (4 * 5)");
+    assert_eq!((status, answer["answer"].as_str()), (200, Some("46")));
+}
+
+#[test]
+fn reports_failures_as_json() {
+    let address = start();
+    let (status, error) = request(address, "POST", "/day/8/part/1", "nop +0\nacc +1\njmp four");
+    assert_eq!(status, 422);
+    assert_eq!(error["error"], "malformed input");
+    assert_eq!((error["line"].as_u64(), error["column"].as_u64()), (Some(3), Some(5)));
+
+    assert_eq!(request(address, "GET", "/day/8/part/1", "").0, 405);
+    assert_eq!(request(address, "POST", "/day/42/part/1", "").0, 404);
+    assert_eq!(request(address, "POST", "/", "").0, 404);
+}
+
+#[test]
+fn rejects_masks_with_too_many_floating_bits() {
+    let address = start();
+    let program = format!("mask = {}\nmem[8] = 11", "X".repeat(36));
+    let (status, error) = request(address, "POST", "/day/14/part/2", &program);
+    assert_eq!(status, 422);
+    assert_eq!(error["error"], "no answer");
+    assert_eq!(error["expected"], "masks with at most 16 floating bits for the version 2 decoder");
+    let (status, answer) = request(address, "POST", "/day/14/part/1", &program);
+    assert_eq!(status, 200);
+    assert_eq!(answer["answer"], "11");
+}